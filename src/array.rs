@@ -0,0 +1,106 @@
+//! Building arrays of characters at compile time.
+
+use {AFTER_SUR, BEFORE_SUR, SUR_END, SUR_START};
+
+/// Create a `[char; N]` holding the characters from `start` to `end`,
+/// inclusive, computed at compile time.
+///
+/// The surrogate range is skipped, as with `new`, and `N` is worked
+/// out from the endpoints. Since the expansion is a constant, it can
+/// initialize a `static` or `const`, which is useful for lookup
+/// tables that have to live in read-only memory.
+///
+/// ```rust
+/// #[macro_use]
+/// extern crate char_iter;
+///
+/// static LOWER: [char; 26] = char_array!('a'..='z');
+///
+/// fn main() {
+///     assert_eq!(LOWER[3], 'd');
+///     assert_eq!(char_array!('\u{D7FF}'..='\u{E000}'), ['\u{D7FF}', '\u{E000}']);
+/// }
+/// ```
+///
+/// Compilation fails if `start > end`.
+#[macro_export]
+macro_rules! char_array {
+    ($start:tt ..= $end:tt) => {{
+        const ARRAY: [char; $crate::__char_array_len($start, $end)] = $crate::__char_array($start);
+        ARRAY
+    }};
+}
+
+#[doc(hidden)]
+pub const fn __char_array_len(start: char, end: char) -> usize {
+    assert!(start as u32 <= end as u32);
+    let (start, end) = (start as u32, end as u32);
+    let naive_count = (end - start + 1) as usize;
+    if start <= BEFORE_SUR && end >= AFTER_SUR {
+        naive_count - (SUR_END - SUR_START + 1) as usize
+    } else {
+        naive_count
+    }
+}
+
+#[doc(hidden)]
+pub const fn __char_array<const N: usize>(start: char) -> [char; N] {
+    let mut out = ['\0'; N];
+    let mut val = start as u32;
+    let mut i = 0;
+    while i < N {
+        if val == SUR_START {
+            val = AFTER_SUR;
+        }
+        out[i] = const_scalar(val);
+        val += 1;
+        i += 1;
+    }
+    out
+}
+
+/// Convert `val`, which must be a Unicode scalar value, to a `char`
+/// in a constant context, like `scalar`.
+///
+/// `char::from_u32_unchecked` can only be used in constants from
+/// Rust 1.81, so this has to transmute.
+#[cfg(not(feature = "safe"))]
+#[allow(unknown_lints, unnecessary_transmutes)]
+const fn const_scalar(val: u32) -> char {
+    unsafe {::core::mem::transmute::<u32, char>(val)}
+}
+// `char::from_u32` is only usable in constants from Rust 1.67, which
+// is why the `safe` feature needs that version.
+#[cfg(feature = "safe")]
+const fn const_scalar(val: u32) -> char {
+    match ::core::char::from_u32(val) {
+        Some(c) => c,
+        None => panic!("not a scalar value"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    const DIGITS: [char; 10] = char_array!('0'..='9');
+
+    #[test]
+    fn smoke() {
+        assert_eq!(DIGITS, ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9']);
+        assert_eq!(char_array!('a'..='a'), ['a']);
+    }
+    #[test]
+    fn surrogates() {
+        let a = char_array!('\u{D7F0}'..='\u{E00F}');
+        assert_eq!(a.len(), 32);
+        assert!(a.iter().cloned().eq(new('\u{D7F0}', '\u{E00F}')));
+        let a = char_array!('\u{E000}'..='\u{E002}');
+        assert_eq!(a, ['\u{E000}', '\u{E001}', '\u{E002}']);
+    }
+    #[test]
+    fn large() {
+        static BMP: [char; 0x10000 - 2048] = char_array!('\u{0}'..='\u{FFFF}');
+        assert!(BMP.iter().cloned().eq(new('\u{0}', '\u{FFFF}')));
+    }
+}
@@ -0,0 +1,70 @@
+//! List a range of characters with their code points, UTF-8
+//! encodings and names.
+//!
+//! ```text
+//! $ charlist a c
+//! U+0061   61           a  LATIN SMALL LETTER A
+//! U+0062   62           b  LATIN SMALL LETTER B
+//! U+0063   63           c  LATIN SMALL LETTER C
+//! ```
+//!
+//! Endpoints are either a single character, or a code point written
+//! as `U+XXXX` or `0xXXXX`. Install it with `cargo install char-iter
+//! --features cli`.
+
+extern crate char_iter;
+
+use std::io::{self, Write};
+use std::{char, env, process};
+
+const USAGE: &str = "usage: charlist START [END]";
+
+/// Interpret `arg` as a single character or a hexadecimal code point.
+fn parse_char(arg: &str) -> Result<char, String> {
+    let mut chars = arg.chars();
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(c)
+    }
+    let hex = if arg.starts_with("U+") || arg.starts_with("u+") || arg.starts_with("0x") {
+        &arg[2..]
+    } else {
+        return Err(format!("expected a character or code point, found `{}`", arg))
+    };
+    u32::from_str_radix(hex, 16).ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("`{}` is not a Unicode scalar value", arg))
+}
+
+fn run() -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (start, end) = match args.len() {
+        1 => { let c = parse_char(&args[0])?; (c, c) }
+        2 => (parse_char(&args[0])?, parse_char(&args[1])?),
+        _ => return Err(USAGE.to_string()),
+    };
+    if start > end {
+        return Err(format!("start U+{:04X} is after end U+{:04X}", start as u32, end as u32))
+    }
+
+    let stdout = io::stdout();
+    let mut out = io::BufWriter::new(stdout.lock());
+    let mut buf = [0; 4];
+    for (c, name) in char_iter::new(start, end).names() {
+        let bytes: Vec<String> = c.encode_utf8(&mut buf).bytes().map(|b| format!("{:02X}", b)).collect();
+        let shown: String = c.escape_debug().collect();
+        let code = format!("U+{:04X}", c as u32);
+        let line = writeln!(out, "{:<8} {:<11}  {}  {}", code, bytes.join(" "), shown, name);
+        if line.is_err() {
+            // the reader went away (e.g. `charlist ... | head`)
+            return Ok(())
+        }
+    }
+    out.flush().or(Ok(()))
+}
+
+fn main() {
+    if let Err(e) = run() {
+        let _ = writeln!(io::stderr(), "charlist: {}", e);
+        process::exit(2);
+    }
+}
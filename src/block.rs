@@ -0,0 +1,146 @@
+//! Unicode blocks.
+
+use std::cmp::Ordering;
+
+use tables::BLOCKS;
+use new;
+use Block;
+use Iter;
+
+impl Block {
+    /// The block containing `c`, if any.
+    ///
+    /// ```rust
+    /// use char_iter::Block;
+    ///
+    /// assert_eq!(Block::of('λ'), Some(Block::GreekAndCoptic));
+    /// assert_eq!(Block::of('\u{2FE0}'), None);
+    /// ```
+    pub fn of(c: char) -> Option<Block> {
+        BLOCKS.binary_search_by(|&(_, start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }).ok().map(|i| BLOCKS[i].0)
+    }
+
+    /// An iterator over every block, in code point order.
+    pub fn all() -> Blocks {
+        Blocks { front: 0, back: BLOCKS.len() }
+    }
+
+    /// The official name of this block, like `"Basic Latin"`.
+    pub fn name(self) -> &'static str {
+        BLOCKS[self as usize].3
+    }
+
+    /// The first and last characters of this block.
+    pub fn range(self) -> (char, char) {
+        let (_, start, end, _) = BLOCKS[self as usize];
+        (start, end)
+    }
+
+    /// An iterator over all the characters of this block.
+    ///
+    /// ```rust
+    /// use char_iter::Block;
+    ///
+    /// assert_eq!(Block::CjkUnifiedIdeographs.chars().len(), 20992);
+    /// ```
+    pub fn chars(self) -> Iter {
+        let (start, end) = self.range();
+        new(start, end)
+    }
+}
+
+/// An iterator over all Unicode blocks.
+///
+/// This is constructed by `Block::all`.
+pub struct Blocks {
+    front: usize,
+    back: usize,
+}
+
+impl Iterator for Blocks {
+    type Item = Block;
+
+    fn next(&mut self) -> Option<Block> {
+        if self.front == self.back {
+            return None
+        }
+        self.front += 1;
+        Some(BLOCKS[self.front - 1].0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+impl DoubleEndedIterator for Blocks {
+    fn next_back(&mut self) -> Option<Block> {
+        if self.front == self.back {
+            return None
+        }
+        self.back -= 1;
+        Some(BLOCKS[self.back].0)
+    }
+}
+
+impl ExactSizeIterator for Blocks {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table_order() {
+        // `name` and `range` index the table by discriminant
+        for (i, block) in Block::all().enumerate() {
+            assert_eq!(block as usize, i);
+        }
+        for (a, b) in Block::all().zip(Block::all().skip(1)) {
+            assert!(a.range().1 < b.range().0);
+        }
+    }
+    #[test]
+    fn of() {
+        assert_eq!(Block::of('a'), Some(Block::BasicLatin));
+        assert_eq!(Block::of('\u{7F}'), Some(Block::BasicLatin));
+        assert_eq!(Block::of('\u{80}'), Some(Block::Latin1Supplement));
+        assert_eq!(Block::of('\u{E000}'), Some(Block::PrivateUseArea));
+        assert_eq!(Block::of('\u{10FFFF}'), Some(Block::SupplementaryPrivateUseAreaB));
+        for block in Block::all() {
+            let (start, end) = block.range();
+            assert_eq!(Block::of(start), Some(block));
+            assert_eq!(Block::of(end), Some(block));
+        }
+        assert_eq!(Block::of('\u{18B0}'), Some(Block::UnifiedCanadianAboriginalSyllabicsExtended));
+        assert_eq!(Block::of('\u{11AB0}'), Some(Block::UnifiedCanadianAboriginalSyllabicsExtendedA));
+    }
+    #[test]
+    fn assigned_have_blocks() {
+        let missing: Vec<char> = new('\u{0}', '\u{10FFFF}').assigned_only()
+            .filter(|&c| Block::of(c).is_none())
+            .collect();
+        assert_eq!(missing, &[] as &[char]);
+    }
+    #[test]
+    fn name_and_range() {
+        assert_eq!(Block::BasicLatin.name(), "Basic Latin");
+        assert_eq!(Block::BasicLatin.range(), ('\u{0}', '\u{7F}'));
+        let v: Vec<char> = Block::Latin1Supplement.chars().rev().take(2).collect();
+        assert_eq!(v, &['ÿ', 'þ']);
+    }
+    #[test]
+    fn all_rev() {
+        assert_eq!(Block::all().len(), Block::all().count());
+        let mut blocks = Block::all();
+        assert_eq!(blocks.next(), Some(Block::BasicLatin));
+        assert_eq!(blocks.next_back(), Some(Block::SupplementaryPrivateUseAreaB));
+    }
+}
@@ -0,0 +1,261 @@
+//! Case-mapping adaptors.
+
+use core::char;
+
+use Iter;
+#[cfg(feature = "alloc")]
+use Ranges;
+
+/// The most characters that a single character's full case mapping
+/// can expand to (for example, `'ΐ'` uppercases to three).
+const MAX_EXPANSION: usize = 3;
+
+macro_rules! case_adaptor {
+    ($name: ident, $std: ident, $method: ident, $desc: expr) => {
+        #[doc = concat!("An iterator adaptor over the ", $desc, " mappings of each character.")]
+        ///
+        /// A character can map to several characters (like `'ß'` to
+        /// `"SS"`), so the length is not known exactly, but the size
+        /// hint gives tight bounds: at least one and at most three
+        /// characters for each remaining input character.
+        pub struct $name<I> {
+            iter: I,
+            front: Option<char::$std>,
+            back: Option<char::$std>,
+        }
+
+        impl<I> $name<I> {
+            fn new(iter: I) -> $name<I> {
+                $name {
+                    iter,
+                    front: None,
+                    back: None,
+                }
+            }
+        }
+
+        impl<I: Iterator<Item = char>> Iterator for $name<I> {
+            type Item = char;
+
+            fn next(&mut self) -> Option<char> {
+                loop {
+                    if let Some(ref mut front) = self.front {
+                        if let Some(c) = front.next() {
+                            return Some(c)
+                        }
+                    }
+                    match self.iter.next() {
+                        Some(c) => self.front = Some(c.$method()),
+                        None => return self.back.as_mut().and_then(|b| b.next()),
+                    }
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let buffered = self.front.as_ref().map_or(0, |f| f.len())
+                    + self.back.as_ref().map_or(0, |b| b.len());
+                let (lo, hi) = self.iter.size_hint();
+                let hi = hi.and_then(|hi| hi.checked_mul(MAX_EXPANSION))
+                    .and_then(|hi| hi.checked_add(buffered));
+                (lo.saturating_add(buffered), hi)
+            }
+        }
+        impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for $name<I> {
+            fn next_back(&mut self) -> Option<char> {
+                loop {
+                    if let Some(ref mut back) = self.back {
+                        if let Some(c) = back.next_back() {
+                            return Some(c)
+                        }
+                    }
+                    match self.iter.next_back() {
+                        Some(c) => self.back = Some(c.$method()),
+                        None => return self.front.as_mut().and_then(|f| f.next_back()),
+                    }
+                }
+            }
+        }
+    }
+}
+
+case_adaptor!(ToUppercase, ToUppercase, to_uppercase, "uppercase");
+case_adaptor!(ToLowercase, ToLowercase, to_lowercase, "lowercase");
+
+/// An iterator adaptor yielding `(lower, upper)` for each lowercase
+/// character `lower` that has a simple uppercase mapping `upper`.
+///
+/// These are the Simple_Uppercase_Mapping entries of UnicodeData.txt
+/// restricted to lowercase characters: uncased characters, and
+/// uppercase and titlecase ones (like `'ǅ'`), are skipped.
+///
+/// This is constructed by the `case_pairs` method on `Iter` and
+/// `Ranges`.
+pub struct CasePairs<I> {
+    iter: I,
+}
+
+/// The simple uppercase mapping of `c`, if it differs from `c`.
+///
+/// The full mapping from `char::to_uppercase` is the simple one
+/// whenever it is a single character. The only characters whose full
+/// mapping expands but which still have a simple mapping are the
+/// Greek letters with ypogegrammeni, which map to the same letter with
+/// prosgegrammeni.
+fn simple_uppercase(c: char) -> Option<char> {
+    match c {
+        '\u{1F80}'..='\u{1F87}' | '\u{1F90}'..='\u{1F97}' | '\u{1FA0}'..='\u{1FA7}' => {
+            char::from_u32(c as u32 + 8)
+        }
+        '\u{1FB3}' | '\u{1FC3}' | '\u{1FF3}' => char::from_u32(c as u32 + 9),
+        _ => {
+            let mut upper = c.to_uppercase();
+            match (upper.next(), upper.next()) {
+                (Some(u), None) if u != c => Some(u),
+                _ => None,
+            }
+        }
+    }
+}
+
+fn case_pair(c: char) -> Option<(char, char)> {
+    if !c.is_lowercase() {
+        return None
+    }
+    simple_uppercase(c).map(|u| (c, u))
+}
+
+impl<I: Iterator<Item = char>> Iterator for CasePairs<I> {
+    type Item = (char, char);
+
+    fn next(&mut self) -> Option<(char, char)> {
+        self.iter.by_ref().filter_map(case_pair).next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for CasePairs<I> {
+    fn next_back(&mut self) -> Option<(char, char)> {
+        self.iter.by_ref().rev().filter_map(case_pair).next()
+    }
+}
+
+impl Iter {
+    /// Map each character to its uppercase equivalent, as
+    /// `char::to_uppercase`.
+    ///
+    /// ```rust
+    /// let upper = char_iter::new('ß', 'ß').to_uppercase();
+    /// assert_eq!(upper.size_hint(), (1, Some(3)));
+    /// assert_eq!(upper.collect::<String>(), "SS");
+    /// ```
+    pub fn to_uppercase(self) -> ToUppercase<Iter> {
+        ToUppercase::new(self)
+    }
+
+    /// Map each character to its lowercase equivalent, as
+    /// `char::to_lowercase`.
+    ///
+    /// ```rust
+    /// let s: String = char_iter::new('A', 'F').to_lowercase().collect();
+    /// assert_eq!(s, "abcdef");
+    /// ```
+    pub fn to_lowercase(self) -> ToLowercase<Iter> {
+        ToLowercase::new(self)
+    }
+
+    /// Pair each lowercase character with its simple uppercase
+    /// mapping, skipping characters that are not lowercase or have no
+    /// such mapping.
+    ///
+    /// ```rust
+    /// let pairs: Vec<(char, char)> = char_iter::new('Z', 'b').case_pairs().collect();
+    /// assert_eq!(pairs, &[('a', 'A'), ('b', 'B')]);
+    /// ```
+    pub fn case_pairs(self) -> CasePairs<Iter> {
+        CasePairs { iter: self }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ranges {
+    /// Map each character to its uppercase equivalent, as
+    /// `Iter::to_uppercase`.
+    pub fn to_uppercase(self) -> ToUppercase<Ranges> {
+        ToUppercase::new(self)
+    }
+
+    /// Map each character to its lowercase equivalent, as
+    /// `Iter::to_lowercase`.
+    pub fn to_lowercase(self) -> ToLowercase<Ranges> {
+        ToLowercase::new(self)
+    }
+
+    /// Pair each lowercase character with its simple uppercase
+    /// mapping, as `Iter::case_pairs`.
+    pub fn case_pairs(self) -> CasePairs<Ranges> {
+        CasePairs { iter: self }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn uppercase() {
+        let s: String = new('a', 'f').to_uppercase().collect();
+        assert_eq!(s, "ABCDEF");
+        let s: String = new('a', 'f').to_uppercase().rev().collect();
+        assert_eq!(s, "FEDCBA");
+    }
+    #[test]
+    fn expansion() {
+        // U+FB00 LATIN SMALL LIGATURE FF, U+FB01 LATIN SMALL LIGATURE FI
+        let mut iter = new('\u{FB00}', '\u{FB01}').to_uppercase();
+        assert_eq!(iter.size_hint(), (2, Some(6)));
+        assert_eq!(iter.next(), Some('F'));
+        assert_eq!(iter.size_hint(), (2, Some(4)));
+        assert_eq!(iter.next_back(), Some('I'));
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+        let rest: String = iter.collect();
+        assert_eq!(rest, "FF");
+    }
+    #[test]
+    fn lowercase() {
+        let s: String = new('\u{130}', '\u{131}').to_lowercase().collect();
+        assert_eq!(s, "i\u{307}\u{131}");
+        let s: String = new('\u{130}', '\u{131}').to_lowercase().rev().collect();
+        assert_eq!(s, "\u{131}\u{307}i");
+    }
+    #[test]
+    fn case_pairs() {
+        let pairs: Vec<(char, char)> = new('\u{0}', '\u{7F}').case_pairs().collect();
+        assert_eq!(pairs.len(), 26);
+        assert_eq!(pairs[0], ('a', 'A'));
+        // 'ß' uppercases to "SS", 'à' to 'À'
+        let pairs: Vec<(char, char)> = new('ß', 'à').case_pairs().rev().collect();
+        assert_eq!(pairs, &[('à', 'À')]);
+        // only the lowercase 'ǆ' pairs, not the titlecase 'ǅ'
+        let pairs: Vec<(char, char)> = new('\u{1C4}', '\u{1C6}').case_pairs().collect();
+        assert_eq!(pairs, &[('\u{1C6}', '\u{1C4}')]);
+        // U+1F80 fully uppercases to "ἈΙ", but simply to U+1F88
+        let pairs: Vec<(char, char)> = new('\u{1F80}', '\u{1F80}').case_pairs().collect();
+        assert_eq!(pairs, &[('\u{1F80}', '\u{1F88}')]);
+        let pairs: Vec<(char, char)> = new('\u{1FB3}', '\u{1FB3}').case_pairs().collect();
+        assert_eq!(pairs, &[('\u{1FB3}', '\u{1FBC}')]);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn bounds_hold() {
+        let iter = new('\u{0}', '\u{10FFFF}').to_uppercase();
+        let (lo, hi) = iter.size_hint();
+        let count = iter.count();
+        assert!(lo <= count && count <= hi.unwrap());
+        let iter = new('\u{0}', '\u{10FFFF}').printable_only().to_lowercase();
+        let (lo, hi) = iter.size_hint();
+        let count = iter.count();
+        assert!(lo <= count && count <= hi.unwrap());
+    }
+}
@@ -0,0 +1,122 @@
+//! Splitting a range into sub-ranges.
+
+use {forward, Iter};
+
+/// An iterator over successive sub-ranges of a range of characters.
+///
+/// This is constructed by the `chunks` method on `Iter`.
+#[derive(Clone)]
+pub struct Chunks {
+    iter: Iter,
+    size: usize,
+}
+
+impl Iter {
+    /// Split the remaining characters into successive `Iter`s of
+    /// `size` characters each. The last one is shorter if the length
+    /// isn't a multiple of `size`.
+    ///
+    /// ```rust
+    /// let lens: Vec<usize> = char_iter::new('a', 'z').chunks(10).map(|c| c.len()).collect();
+    /// assert_eq!(lens, [10, 10, 6]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `size` is 0.
+    pub fn chunks(self, size: usize) -> Chunks {
+        assert!(size != 0);
+        Chunks { iter: self, size }
+    }
+}
+
+impl Iterator for Chunks {
+    type Item = Iter;
+
+    fn next(&mut self) -> Option<Iter> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None
+        }
+        if len <= self.size {
+            let ret = self.iter.clone();
+            self.iter.finished = true;
+            return Some(ret)
+        }
+        let start = self.iter.start;
+        self.iter.start = forward(start, self.size as u32);
+        Some(Iter { start, end: forward(start, self.size as u32 - 1), finished: false })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.len();
+        let n = if len == 0 { 0 } else { (len - 1) / self.size + 1 };
+        (n, Some(n))
+    }
+}
+impl DoubleEndedIterator for Chunks {
+    fn next_back(&mut self) -> Option<Iter> {
+        let len = self.iter.len();
+        if len == 0 {
+            return None
+        }
+        let back = match len % self.size {
+            0 => self.size,
+            rem => rem,
+        };
+        if len == back {
+            let ret = self.iter.clone();
+            self.iter.finished = true;
+            return Some(ret)
+        }
+        let start = forward(self.iter.start, (len - back) as u32);
+        let end = self.iter.end;
+        self.iter.end = forward(self.iter.start, (len - back) as u32 - 1);
+        Some(Iter { start, end, finished: false })
+    }
+}
+
+impl ExactSizeIterator for Chunks {}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn chunks() {
+        let v: Vec<Vec<char>> = new('a', 'g').chunks(3).map(|c| c.collect()).collect();
+        assert_eq!(v, [vec!['a', 'b', 'c'], vec!['d', 'e', 'f'], vec!['g']]);
+        let v: Vec<Vec<char>> = new('a', 'f').chunks(3).map(|c| c.collect()).collect();
+        assert_eq!(v, [vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+        let v: Vec<Vec<char>> = new('a', 'g').chunks(3).rev().map(|c| c.collect()).collect();
+        assert_eq!(v, [vec!['g'], vec!['d', 'e', 'f'], vec!['a', 'b', 'c']]);
+    }
+    #[test]
+    fn surrogates() {
+        let v: Vec<Vec<char>> = new('\u{D7FE}', '\u{E001}').chunks(3).map(|c| c.collect()).collect();
+        assert_eq!(v, [vec!['\u{D7FE}', '\u{D7FF}', '\u{E000}'], vec!['\u{E001}']]);
+    }
+    #[test]
+    fn full_range() {
+        let full = new('\u{0}', '\u{10FFFF}');
+        let mut chunks = full.clone().chunks(1000);
+        assert_eq!(chunks.len(), 1113);
+        let first = chunks.next().unwrap();
+        let last = chunks.next_back().unwrap();
+        assert_eq!(first.len(), 1000);
+        assert_eq!(last.len(), 64);
+        assert_eq!(chunks.len(), 1111);
+        assert!(first.chain(chunks.flatten()).chain(last).eq(full));
+    }
+    #[test]
+    fn empty() {
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert_eq!(iter.chunks(2).count(), 0);
+    }
+    #[should_panic]
+    #[test]
+    fn zero() {
+        new('a', 'b').chunks(0);
+    }
+}
@@ -0,0 +1,101 @@
+//! Grouping characters back into ranges.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::{self, Vec};
+use core::iter::Peekable;
+
+use {next_char, Iter};
+
+/// An iterator adaptor grouping ascending characters into maximal
+/// runs of consecutive characters.
+///
+/// This is constructed by the `coalesce` and `coalesce_unsorted`
+/// functions at the top level.
+pub struct Coalesce<I: Iterator> {
+    iter: Peekable<I>,
+}
+
+/// Group the characters of `iter`, which must be in ascending order,
+/// into maximal runs, each yielded as an `Iter`.
+///
+/// Consecutive characters either side of the surrogate range count
+/// as one run, and repeated characters are ignored. If `iter` isn't
+/// ascending, the output is still a list of runs covering its
+/// characters, but they aren't necessarily maximal.
+///
+/// ```rust
+/// let runs: Vec<(char, char)> = char_iter::coalesce("abcxyz0".chars())
+///     .map(|mut run| (run.next().unwrap(), run.next_back().unwrap_or('-')))
+///     .collect();
+/// assert_eq!(runs, [('a', 'c'), ('x', 'z'), ('0', '-')]);
+/// ```
+pub fn coalesce<I: IntoIterator<Item = char>>(iter: I) -> Coalesce<I::IntoIter> {
+    Coalesce { iter: iter.into_iter().peekable() }
+}
+
+/// Sort the characters of `iter`, and then group them into maximal
+/// runs, as `coalesce`.
+///
+/// ```rust
+/// let runs: Vec<usize> = char_iter::coalesce_unsorted("banana".chars())
+///     .map(|run| run.len())
+///     .collect();
+/// assert_eq!(runs, [2, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub fn coalesce_unsorted<I: IntoIterator<Item = char>>(iter: I) -> Coalesce<vec::IntoIter<char>> {
+    let mut chars: Vec<char> = iter.into_iter().collect();
+    chars.sort_unstable();
+    coalesce(chars)
+}
+
+impl<I: Iterator<Item = char>> Iterator for Coalesce<I> {
+    type Item = Iter;
+
+    fn next(&mut self) -> Option<Iter> {
+        let start = self.iter.next()?;
+        let mut end = start;
+        while let Some(&c) = self.iter.peek() {
+            if c != end && Some(c) != next_char(end) {
+                break
+            }
+            end = c;
+            self.iter.next();
+        }
+        Some(Iter { start, end, finished: false })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lo, hi) = self.iter.size_hint();
+        ((lo != 0) as usize, hi)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use new;
+
+    fn bounds<I: Iterator<Item = Iter>>(runs: I) -> Vec<(char, char)> {
+        runs.map(|r| (r.start, r.end)).collect()
+    }
+
+    #[test]
+    fn coalesce_runs() {
+        assert_eq!(bounds(coalesce(new('a', 'z'))), [('a', 'z')]);
+        assert_eq!(bounds(coalesce("".chars())), []);
+        assert_eq!(bounds(coalesce("aabbd".chars())), [('a', 'b'), ('d', 'd')]);
+        assert_eq!(bounds(coalesce(new('\u{D7FE}', '\u{E001}'))), [('\u{D7FE}', '\u{E001}')]);
+        assert_eq!(bounds(coalesce("ba".chars())), [('b', 'b'), ('a', 'a')]);
+    }
+    #[test]
+    fn coalesce_filtered() {
+        let runs = bounds(coalesce(new('\u{0}', '\u{7F}').filter(|c| c.is_ascii_alphanumeric())));
+        assert_eq!(runs, [('0', '9'), ('A', 'Z'), ('a', 'z')]);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn unsorted() {
+        assert_eq!(bounds(coalesce_unsorted("zyxcba".chars())), [('a', 'c'), ('x', 'z')]);
+    }
+}
@@ -0,0 +1,91 @@
+//! Ordering characters by a locale's collation.
+
+use std::vec;
+
+use icu_collator::CollatorBorrowed;
+
+use Iter;
+use Ranges;
+
+fn collate<I: Iterator<Item = char>>(iter: I, collator: &CollatorBorrowed) -> vec::IntoIter<char> {
+    let mut chars: Vec<char> = iter.collect();
+    // stable, so characters the collation can't tell apart (like
+    // ignorable controls) stay in code point order
+    chars.sort_by(|a, b| {
+        let (mut a_buf, mut b_buf) = ([0; 4], [0; 4]);
+        collator.compare(a.encode_utf8(&mut a_buf), b.encode_utf8(&mut b_buf))
+    });
+    chars.into_iter()
+}
+
+impl Iter {
+    /// Yield the characters in the order given by `collator` (such as
+    /// the collation for a locale), rather than code point order.
+    ///
+    /// Sorting needs every character up front, so this collects the
+    /// range into a buffer.
+    ///
+    /// ```rust
+    /// extern crate char_iter;
+    /// extern crate icu_collator;
+    /// extern crate icu_locale_core;
+    ///
+    /// use icu_collator::Collator;
+    /// use icu_collator::options::CollatorOptions;
+    /// use icu_locale_core::locale;
+    ///
+    /// # fn main() {
+    /// let de = Collator::try_new(locale!("de").into(), CollatorOptions::default()).unwrap();
+    /// let v: Vec<char> = char_iter::new('o', 'ö').collated(&de).collect();
+    /// let position = |c| v.iter().position(|&x| x == c).unwrap();
+    /// // 'ö' sorts with 'o', not after 'z'
+    /// assert!(position('ö') < position('p'));
+    /// # }
+    /// ```
+    pub fn collated(self, collator: &CollatorBorrowed) -> vec::IntoIter<char> {
+        collate(self, collator)
+    }
+}
+
+impl Ranges {
+    /// Yield the characters in the order given by `collator`, as
+    /// `Iter::collated`.
+    pub fn collated(self, collator: &CollatorBorrowed) -> vec::IntoIter<char> {
+        collate(self, collator)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use icu_collator::options::CollatorOptions;
+    use icu_collator::{Collator, CollatorBorrowed};
+    use icu_locale_core::Locale;
+    use new;
+
+    fn collator(name: &str) -> CollatorBorrowed<'static> {
+        let locale: Locale = name.parse().unwrap();
+        Collator::try_new(locale.into(), CollatorOptions::default()).unwrap()
+    }
+
+    fn position(v: &[char], c: char) -> usize {
+        v.iter().position(|&x| x == c).unwrap()
+    }
+
+    #[test]
+    fn german_and_swedish() {
+        let de: Vec<char> = new('a', '\u{FF}').collated(&collator("de")).collect();
+        assert!(position(&de, 'o') < position(&de, 'ö'));
+        assert!(position(&de, 'ö') < position(&de, 'p'));
+
+        let sv: Vec<char> = new('a', '\u{FF}').collated(&collator("sv")).rev().collect();
+        assert!(position(&sv, 'ö') < position(&sv, 'z'));
+    }
+    #[test]
+    fn keeps_every_character() {
+        let v: Vec<char> = new('\u{0}', '\u{7F}').printable_only().collated(&collator("en")).collect();
+        assert_eq!(v.len(), 95);
+        let mut sorted = v.clone();
+        sorted.sort();
+        assert_eq!(sorted, new('\u{0}', '\u{7F}').printable_only().collect::<Vec<_>>());
+    }
+}
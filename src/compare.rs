@@ -0,0 +1,81 @@
+//! Comparing ranges without iterating over them.
+
+use core::cmp::Ordering;
+
+use Iter;
+
+impl Iter {
+    /// The remaining characters, as an inclusive `(start, end)`
+    /// pair, or `None` if there are none.
+    fn bounds(&self) -> Option<(char, char)> {
+        if self.finished { None } else { Some((self.start, self.end)) }
+    }
+
+    /// Whether the remaining characters of `self` and `other` are
+    /// the same, as `Iterator::eq` would find, but in constant time.
+    ///
+    /// ```rust
+    /// let mut a = char_iter::new('a', 'z');
+    /// a.next();
+    /// assert!(a.range_eq(&char_iter::new('b', 'z')));
+    /// assert!(!a.range_eq(&char_iter::new('a', 'z')));
+    /// ```
+    pub fn range_eq(&self, other: &Iter) -> bool {
+        self.bounds() == other.bounds()
+    }
+
+    /// Compare the remaining characters of `self` and `other`
+    /// lexicographically, as `Iterator::cmp` would, but in constant
+    /// time.
+    ///
+    /// ```rust
+    /// use std::cmp::Ordering;
+    ///
+    /// let a = char_iter::new('a', 'c');
+    /// assert_eq!(a.range_cmp(&char_iter::new('b', 'b')), Ordering::Less);
+    /// assert_eq!(a.range_cmp(&char_iter::new('a', 'b')), Ordering::Greater);
+    /// ```
+    pub fn range_cmp(&self, other: &Iter) -> Ordering {
+        match (self.bounds(), other.bounds()) {
+            (None, None) => Ordering::Equal,
+            (None, Some(_)) => Ordering::Less,
+            (Some(_), None) => Ordering::Greater,
+            // the sequences agree until the first one runs out, so
+            // the one that ends first is shorter, and hence less.
+            (Some((a, x)), Some((b, y))) => a.cmp(&b).then(x.cmp(&y)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+    use Iter;
+
+    fn empty() -> Iter {
+        let mut iter = new('a', 'a');
+        iter.next();
+        iter
+    }
+
+    #[test]
+    fn matches_iterator() {
+        let ranges = || vec![
+            empty(), new('a', 'a'), new('a', 'b'), new('a', 'z'), new('b', 'c'),
+            new('\u{D7FF}', '\u{E000}'), new('\u{E000}', '\u{E000}'),
+        ];
+        for a in ranges() {
+            for b in ranges() {
+                assert_eq!(a.range_eq(&b), a.clone().eq(b.clone()));
+                assert_eq!(a.range_cmp(&b), a.clone().cmp(b.clone()));
+            }
+        }
+    }
+    #[test]
+    fn empty_ranges() {
+        let mut a = new('x', 'y');
+        a.next();
+        a.next();
+        assert!(a.range_eq(&empty()));
+    }
+}
@@ -0,0 +1,41 @@
+//! Confusable detection (UTS #39).
+
+use unicode_security::confusable_detection;
+
+/// The UTS #39 skeleton of `c`: the string of prototype characters
+/// that every character visually confusable with `c` maps to.
+///
+/// Two characters (or strings) are confusable when their skeletons
+/// are equal.
+///
+/// ```rust
+/// use char_iter::confusable_skeleton;
+///
+/// // CYRILLIC SMALL LETTER A looks like LATIN SMALL LETTER A
+/// assert_eq!(confusable_skeleton('\u{430}'), confusable_skeleton('a'));
+/// assert_eq!(confusable_skeleton('m'), "rn");
+/// ```
+pub fn confusable_skeleton(c: char) -> String {
+    let mut buf = [0; 4];
+    confusable_detection::skeleton(c.encode_utf8(&mut buf)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use new;
+
+    #[test]
+    fn skeleton() {
+        assert_eq!(confusable_skeleton('0'), "O");
+        assert_eq!(confusable_skeleton('O'), "O");
+        assert_eq!(confusable_skeleton('1'), confusable_skeleton('l'));
+        assert_eq!(confusable_skeleton('x'), "x");
+    }
+    #[test]
+    fn confusable_in_range() {
+        let target = confusable_skeleton('O');
+        let v: Vec<char> = new('\u{0}', '\u{7F}').filter(|&c| confusable_skeleton(c) == target).collect();
+        assert_eq!(v, &['0', 'O']);
+    }
+}
@@ -0,0 +1,87 @@
+//! Filtering while keeping an exact length.
+
+use Iter;
+
+/// An iterator adaptor keeping the characters that satisfy a
+/// predicate, with an exact length.
+///
+/// This is constructed by the `filter_counted` method on `Iter`.
+pub struct FilterCounted<F> {
+    iter: Iter,
+    pred: F,
+    remaining: usize,
+}
+
+impl Iter {
+    /// Keep only the characters for which `pred` returns `true`,
+    /// like `filter`, but implementing `ExactSizeIterator`.
+    ///
+    /// This makes a pass over the range up front to count the
+    /// matches, so `pred` is called twice on each character and it
+    /// should give the same answer both times.
+    ///
+    /// ```rust
+    /// let vowels = char_iter::new('a', 'z').filter_counted(|c| "aeiou".contains(*c));
+    /// assert_eq!(vowels.len(), 5);
+    /// ```
+    pub fn filter_counted<F: FnMut(&char) -> bool>(self, mut pred: F) -> FilterCounted<F> {
+        let remaining = self.clone().filter(|c| pred(c)).count();
+        FilterCounted { iter: self, pred, remaining }
+    }
+}
+
+impl<F: FnMut(&char) -> bool> Iterator for FilterCounted<F> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.remaining == 0 {
+            return None
+        }
+        let ret = self.iter.find(&mut self.pred);
+        debug_assert!(ret.is_some());
+        self.remaining -= 1;
+        ret
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+impl<F: FnMut(&char) -> bool> DoubleEndedIterator for FilterCounted<F> {
+    fn next_back(&mut self) -> Option<char> {
+        if self.remaining == 0 {
+            return None
+        }
+        let ret = self.iter.rfind(&mut self.pred);
+        debug_assert!(ret.is_some());
+        self.remaining -= 1;
+        ret
+    }
+}
+
+impl<F: FnMut(&char) -> bool> ExactSizeIterator for FilterCounted<F> {}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn filter_counted() {
+        let mut iter = new('\u{0}', '\u{10FFFF}').filter_counted(|c| c.is_alphabetic());
+        let mut len = new('\u{0}', '\u{10FFFF}').filter(|c| c.is_alphabetic()).count();
+        assert_eq!(iter.len(), len);
+        assert_eq!(iter.next(), Some('A'));
+        assert_eq!(iter.next_back(), new('\u{0}', '\u{10FFFF}').rev().find(|c| c.is_alphabetic()));
+        len -= 2;
+        assert_eq!(iter.len(), len);
+        assert_eq!(iter.count(), len);
+    }
+    #[test]
+    fn stops_early() {
+        let mut iter = new('a', 'z').filter_counted(|&c| c == 'b');
+        assert_eq!(iter.next(), Some('b'));
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
@@ -0,0 +1,109 @@
+//! Formatting ranges of characters.
+
+use core::fmt;
+
+use {utf8, Iter};
+
+/// A `fmt::Display` adaptor that writes every character of a range.
+///
+/// This is constructed by the `display` method on `Iter`.
+#[derive(Clone)]
+pub struct Display<'a> {
+    iter: Iter,
+    separator: &'a str,
+}
+
+impl Iter {
+    /// Display the remaining characters, one after another.
+    ///
+    /// ```rust
+    /// let s = format!("{}", char_iter::new('α', 'ε').display());
+    /// assert_eq!(s, "αβγδε");
+    /// ```
+    pub fn display(self) -> Display<'static> {
+        Display { iter: self, separator: "" }
+    }
+
+    /// Write the remaining characters into `w`.
+    ///
+    /// Characters are encoded into a small buffer on the stack by
+    /// `encode_utf8_into`, and handed to `w` a chunk at a time, rather
+    /// than one by one.
+    ///
+    /// ```rust
+    /// let mut s = String::from("digits: ");
+    /// char_iter::new('0', '9').write_to(&mut s).unwrap();
+    /// assert_eq!(s, "digits: 0123456789");
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(mut self, w: &mut W) -> fmt::Result {
+        let mut buf = [0; 256];
+        loop {
+            let (n, _) = self.encode_utf8_into(&mut buf);
+            if n == 0 {
+                return Ok(())
+            }
+            w.write_str(utf8(&buf[..n]))?;
+        }
+    }
+}
+
+impl<'a> Display<'a> {
+    /// Write `separator` between each pair of characters.
+    ///
+    /// ```rust
+    /// let s = format!("{}", char_iter::new('a', 'e').display().separator(", "));
+    /// assert_eq!(s, "a, b, c, d, e");
+    /// ```
+    pub fn separator<'b>(self, separator: &'b str) -> Display<'b> {
+        Display { iter: self.iter, separator }
+    }
+}
+
+impl<'a> fmt::Display for Display<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.separator.is_empty() {
+            return self.iter.clone().write_to(f)
+        }
+        for (i, c) in self.iter.clone().enumerate() {
+            if i > 0 {
+                f.write_str(self.separator)?;
+            }
+            fmt::Write::write_char(f, c)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn display() {
+        assert_eq!(new('a', 'e').display().to_string(), "abcde");
+        assert_eq!(new('a', 'a').display().separator("-").to_string(), "a");
+        assert_eq!(new('\u{D7FF}', '\u{E000}').display().separator(" ").to_string(),
+                   "\u{D7FF} \u{E000}");
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert_eq!(iter.display().separator(",").to_string(), "");
+    }
+    #[test]
+    fn write_to() {
+        let mut s = String::new();
+        new('\u{0}', '\u{10FFFF}').write_to(&mut s).unwrap();
+        assert_eq!(s, new('\u{0}', '\u{10FFFF}').collect::<String>());
+
+        let mut iter = new('a', 'a');
+        iter.next();
+        let mut s = String::new();
+        iter.write_to(&mut s).unwrap();
+        assert_eq!(s, "");
+    }
+    #[test]
+    fn display_reusable() {
+        let d = new('x', 'z').display().separator("|");
+        assert_eq!(d.to_string(), "x|y|z");
+        assert_eq!(d.to_string(), "x|y|z");
+    }
+}
@@ -0,0 +1,492 @@
+//! Collecting and encoding ranges of characters.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::{self, Vec};
+use core::cmp;
+
+use {forward, new, Iter};
+#[cfg(feature = "alloc")]
+use {scalar, utf8_string, Ranges, AFTER_SUR, BEFORE_SUR};
+
+/// The ranges of characters with each UTF-8 encoded length.
+const UTF8_CLASSES: [(char, char, u8); 4] = [
+    ('\u{0}', '\u{7F}', 1),
+    ('\u{80}', '\u{7FF}', 2),
+    ('\u{800}', '\u{FFFF}', 3),
+    ('\u{10000}', '\u{10FFFF}', 4),
+];
+
+impl Iter {
+    /// Whether all of the remaining characters are ASCII, and so
+    /// encode as a single byte.
+    ///
+    /// ```rust
+    /// assert!(char_iter::new('a', 'z').is_ascii_only());
+    /// assert!(!char_iter::new('a', 'é').is_ascii_only());
+    /// ```
+    pub fn is_ascii_only(&self) -> bool {
+        self.finished || self.end <= '\u{7F}'
+    }
+
+    /// Whether all of the remaining characters are in the Basic
+    /// Multilingual Plane, and so are a single UTF-16 code unit.
+    ///
+    /// ```rust
+    /// assert!(char_iter::new('a', '\u{FFFF}').is_bmp_only());
+    /// assert!(!char_iter::new('a', '\u{10000}').is_bmp_only());
+    /// ```
+    pub fn is_bmp_only(&self) -> bool {
+        self.finished || self.end <= '\u{FFFF}'
+    }
+
+    /// The number of bytes in the UTF-8 encoding of the remaining
+    /// characters, computed without iterating.
+    pub(crate) fn utf8_len(&self) -> usize {
+        if self.finished {
+            return 0
+        }
+        UTF8_CLASSES.iter().map(|&(lo, hi, bytes)| {
+            let (lo, hi) = (cmp::max(lo, self.start), cmp::min(hi, self.end));
+            if lo <= hi { new(lo, hi).len() * bytes as usize } else { 0 }
+        }).sum()
+    }
+
+    /// Split the remaining characters into the sub-ranges whose
+    /// UTF-8 encodings are 1, 2, 3 and 4 bytes long, skipping any that
+    /// are empty.
+    ///
+    /// ```rust
+    /// let segments: Vec<_> = char_iter::new('~', 'ā').utf8_len_segments()
+    ///     .map(|(iter, len)| (iter.count(), len))
+    ///     .collect();
+    /// assert_eq!(segments, [(2, 1), (130, 2)]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn utf8_len_segments(self) -> vec::IntoIter<(Iter, u8)> {
+        let mut segments = Vec::new();
+        if !self.finished {
+            for &(lo, hi, bytes) in &UTF8_CLASSES {
+                let (lo, hi) = (cmp::max(lo, self.start), cmp::min(hi, self.end));
+                if lo <= hi {
+                    segments.push((new(lo, hi), bytes));
+                }
+            }
+        }
+        segments.into_iter()
+    }
+
+    /// Collect the remaining characters into a `String`, allocating
+    /// exactly the right number of bytes up front.
+    ///
+    /// `collect::<String>()` can only reserve based on the number of
+    /// characters, and so reallocates as multi-byte characters push
+    /// it over capacity. (`TrustedLen`, implemented with the
+    /// `unstable` feature, only lets `Vec` avoid this: `String`'s
+    /// `Extend` can't be specialized outside the standard library.)
+    ///
+    /// ```rust
+    /// let s = char_iter::new('α', 'ω').collect_string();
+    /// assert_eq!(s, "αβγδεζηθικλμνξοπρςστυφχψω");
+    /// assert_eq!(s.capacity(), s.len());
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn collect_string(mut self) -> String {
+        let mut bytes = vec![0; self.utf8_len()];
+        let (len, _) = self.encode_utf8_into(&mut bytes);
+        debug_assert_eq!(len, bytes.len());
+        utf8_string(bytes)
+    }
+
+    /// Collect the remaining characters into a `Vec`, allocating
+    /// exactly `len()` elements.
+    ///
+    /// ```rust
+    /// let v = char_iter::new('a', 'e').to_vec();
+    /// assert_eq!(v, ['a', 'b', 'c', 'd', 'e']);
+    /// assert_eq!(v.capacity(), 5);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn to_vec(self) -> Vec<char> {
+        let mut v = Vec::with_capacity(self.len());
+        self.extend_vec(&mut v);
+        v
+    }
+
+    /// Append the remaining characters to `v`.
+    ///
+    /// This reserves exactly the right amount of space, and then
+    /// fills it with one loop on each side of the surrogate range,
+    /// neither of which needs to check for the gap. This is
+    /// noticeably faster than `v.extend(iter)` for large ranges.
+    ///
+    /// ```rust
+    /// let mut v = vec!['x'];
+    /// char_iter::new('\u{D7FF}', '\u{E000}').extend_vec(&mut v);
+    /// assert_eq!(v, ['x', '\u{D7FF}', '\u{E000}']);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn extend_vec(self, v: &mut Vec<char>) {
+        if self.finished {
+            return
+        }
+        v.reserve_exact(self.len());
+        let (start, end) = (self.start as u32, self.end as u32);
+        if start <= BEFORE_SUR {
+            let hi = cmp::min(end, BEFORE_SUR);
+            v.extend((start..=hi).map(scalar));
+        }
+        if end >= AFTER_SUR {
+            let lo = cmp::max(start, AFTER_SUR);
+            v.extend((lo..=end).map(scalar));
+        }
+    }
+
+    /// Iterate over the bytes of the UTF-8 encoding of the remaining
+    /// characters.
+    ///
+    /// ```rust
+    /// let bytes: Vec<u8> = char_iter::new('\u{7F}', '\u{80}').utf8_bytes().collect();
+    /// assert_eq!(bytes, [0x7F, 0xC2, 0x80]);
+    /// ```
+    pub fn utf8_bytes(self) -> Utf8Bytes {
+        Utf8Bytes { iter: self, buf: [0; 4], pos: 0, len: 0 }
+    }
+
+    /// Encode as many of the remaining characters as fit into `buf`
+    /// as UTF-8, returning the number of bytes and the number of
+    /// characters written.
+    ///
+    /// The iterator is advanced past the characters that were
+    /// written, so this can be called repeatedly to fill a series of
+    /// buffers. Each run of characters with the same encoded length
+    /// is written by its own loop, which avoids `char::encode_utf8`'s
+    /// per-character branching on the length.
+    ///
+    /// ```rust
+    /// let mut iter = char_iter::new('x', 'ä');
+    /// let mut buf = [0; 8];
+    /// assert_eq!(iter.encode_utf8_into(&mut buf), (8, 8));
+    /// assert_eq!(&buf, b"xyz{|}~\x7F");
+    /// assert_eq!(iter.encode_utf8_into(&mut buf), (8, 4));
+    /// assert_eq!(&buf, "\u{80}\u{81}\u{82}\u{83}".as_bytes());
+    /// ```
+    pub fn encode_utf8_into(&mut self, buf: &mut [u8]) -> (usize, usize) {
+        let (mut bytes, mut chars) = (0, 0);
+        while !self.finished {
+            let start = self.start as u32;
+            let (class_end, width) = match start {
+                0..=0x7F => (0x7F, 1),
+                0x80..=0x7FF => (0x7FF, 2),
+                0x800..=0xFFFF => (if start < ::SUR_START { ::BEFORE_SUR } else { 0xFFFF }, 3),
+                _ => (0x10FFFF, 4),
+            };
+            let run = cmp::min(class_end, self.end as u32) - start + 1;
+            let n = cmp::min(run as usize, (buf.len() - bytes) / width);
+            if n == 0 {
+                break
+            }
+            let out = &mut buf[bytes..bytes + n * width];
+            match width {
+                1 => for (b, v) in out.iter_mut().zip(start..) {
+                    *b = v as u8;
+                },
+                2 => for (b, v) in out.chunks_exact_mut(2).zip(start..) {
+                    b[0] = 0xC0 | (v >> 6) as u8;
+                    b[1] = 0x80 | (v & 0x3F) as u8;
+                },
+                3 => for (b, v) in out.chunks_exact_mut(3).zip(start..) {
+                    b[0] = 0xE0 | (v >> 12) as u8;
+                    b[1] = 0x80 | ((v >> 6) & 0x3F) as u8;
+                    b[2] = 0x80 | (v & 0x3F) as u8;
+                },
+                _ => for (b, v) in out.chunks_exact_mut(4).zip(start..) {
+                    b[0] = 0xF0 | (v >> 18) as u8;
+                    b[1] = 0x80 | ((v >> 12) & 0x3F) as u8;
+                    b[2] = 0x80 | ((v >> 6) & 0x3F) as u8;
+                    b[3] = 0x80 | (v & 0x3F) as u8;
+                },
+            }
+            bytes += n * width;
+            chars += n;
+            if n == run as usize && class_end >= self.end as u32 {
+                self.finished = true;
+            } else {
+                self.start = forward(self.start, n as u32);
+            }
+        }
+        (bytes, chars)
+    }
+
+    /// The number of code units in the UTF-16 encoding of the
+    /// remaining characters, computed without iterating.
+    fn utf16_len(&self) -> usize {
+        if self.finished || self.end < '\u{10000}' {
+            return self.len()
+        }
+        self.len() + new(cmp::max('\u{10000}', self.start), self.end).len()
+    }
+
+    /// Iterate over the code units of the UTF-16 encoding of the
+    /// remaining characters, with characters outside the Basic
+    /// Multilingual Plane becoming surrogate pairs.
+    ///
+    /// ```rust
+    /// let units: Vec<u16> = char_iter::new('\u{FFFF}', '\u{10000}').utf16_units().collect();
+    /// assert_eq!(units, [0xFFFF, 0xD800, 0xDC00]);
+    /// ```
+    pub fn utf16_units(self) -> Utf16Units {
+        Utf16Units { iter: self, trail: None }
+    }
+}
+
+/// An iterator over the UTF-8 encoding of a range of characters.
+///
+/// This is constructed by the `utf8_bytes` method on `Iter`.
+#[derive(Clone)]
+pub struct Utf8Bytes {
+    iter: Iter,
+    buf: [u8; 4],
+    pos: usize,
+    len: usize,
+}
+
+impl Iterator for Utf8Bytes {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        if self.pos == self.len {
+            let c = self.iter.next()?;
+            self.len = c.encode_utf8(&mut self.buf).len();
+            self.pos = 0;
+        }
+        self.pos += 1;
+        Some(self.buf[self.pos - 1])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.utf8_len() + (self.len - self.pos);
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Utf8Bytes {}
+
+/// An iterator over the UTF-16 encoding of a range of characters.
+///
+/// This is constructed by the `utf16_units` method on `Iter`.
+#[derive(Clone)]
+pub struct Utf16Units {
+    iter: Iter,
+    trail: Option<u16>,
+}
+
+impl Iterator for Utf16Units {
+    type Item = u16;
+
+    fn next(&mut self) -> Option<u16> {
+        if let Some(u) = self.trail.take() {
+            return Some(u)
+        }
+        let c = self.iter.next()?;
+        let mut buf = [0; 2];
+        let units = c.encode_utf16(&mut buf);
+        if units.len() == 2 {
+            self.trail = Some(units[1]);
+        }
+        Some(units[0])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.iter.utf16_len() + self.trail.is_some() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Utf16Units {}
+
+#[cfg(feature = "alloc")]
+impl Ranges {
+    /// Whether all of the remaining characters are ASCII, as
+    /// `Iter::is_ascii_only`.
+    pub fn is_ascii_only(&self) -> bool {
+        self.ranges.iter().all(Iter::is_ascii_only)
+    }
+
+    /// Whether all of the remaining characters are in the Basic
+    /// Multilingual Plane, as `Iter::is_bmp_only`.
+    pub fn is_bmp_only(&self) -> bool {
+        self.ranges.iter().all(Iter::is_bmp_only)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn utf8_len() {
+        assert_eq!(new('a', 'z').utf8_len(), 26);
+        assert_eq!(new('\u{7F}', '\u{80}').utf8_len(), 1 + 2);
+        assert_eq!(new('\u{D7FF}', '\u{E000}').utf8_len(), 3 + 3);
+        assert_eq!(new('\u{FFFF}', '\u{10000}').utf8_len(), 3 + 4);
+        let full = new('\u{0}', '\u{10FFFF}');
+        assert_eq!(full.utf8_len(), full.map(|c| c.len_utf8()).sum::<usize>());
+    }
+    #[test]
+    fn utf8_len_partial() {
+        let mut iter = new('\u{7E}', '\u{10000}');
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.utf8_len(), new('\u{7F}', '\u{FFFF}').map(|c| c.len_utf8()).sum::<usize>());
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert_eq!(iter.utf8_len(), 0);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn collect_string() {
+        let s = new('\u{0}', '\u{10FFFF}').collect_string();
+        assert_eq!(s.capacity(), s.len());
+        assert_eq!(s, new('\u{0}', '\u{10FFFF}').collect::<String>());
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_vec() {
+        let v = new('\u{0}', '\u{10FFFF}').to_vec();
+        assert_eq!(v.capacity(), v.len());
+        assert_eq!(v, new('\u{0}', '\u{10FFFF}').collect::<Vec<_>>());
+
+        let mut iter = new('a', 'c');
+        iter.next();
+        assert_eq!(iter.to_vec(), ['b', 'c']);
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert!(iter.to_vec().is_empty());
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn extend_vec() {
+        for &(lo, hi) in &[('\u{0}', '\u{10FFFF}'), ('a', 'z'), ('\u{D7F0}', '\u{D7FF}'),
+                           ('\u{E000}', '\u{E010}'), ('\u{D7FF}', '\u{E000}')] {
+            let mut v = vec!['!'];
+            new(lo, hi).extend_vec(&mut v);
+            assert_eq!(v[0], '!');
+            assert_eq!(v[1..], new(lo, hi).collect::<Vec<_>>()[..]);
+        }
+        let mut iter = new('a', 'c');
+        iter.next();
+        iter.next_back();
+        let mut v = vec![];
+        iter.extend_vec(&mut v);
+        assert_eq!(v, ['b']);
+    }
+    #[test]
+    fn utf8_bytes() {
+        let s: String = new('\u{0}', '\u{10FFFF}').collect();
+        assert!(new('\u{0}', '\u{10FFFF}').utf8_bytes().eq(s.bytes()));
+    }
+    #[test]
+    fn utf8_bytes_len() {
+        let mut iter = new('\u{7F}', '\u{10000}').utf8_bytes();
+        let mut len = iter.len();
+        assert_eq!(len, 1 + 1920 * 2 + (0x10000 - 0x800 - 2048) * 3 + 4);
+        while let Some(_) = iter.next() {
+            len -= 1;
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(len, 0);
+    }
+    #[test]
+    fn utf16_units() {
+        let s: String = new('\u{0}', '\u{10FFFF}').collect();
+        assert!(new('\u{0}', '\u{10FFFF}').utf16_units().eq(s.encode_utf16()));
+    }
+    #[test]
+    fn utf16_units_len() {
+        let mut iter = new('\u{FFFE}', '\u{10002}').utf16_units();
+        let mut len = iter.len();
+        assert_eq!(len, 2 + 3 * 2);
+        while let Some(_) = iter.next() {
+            len -= 1;
+            assert_eq!(iter.len(), len);
+        }
+        assert_eq!(len, 0);
+        assert_eq!(new('a', 'z').utf16_units().len(), 26);
+    }
+    #[test]
+    fn encode_utf8_into() {
+        let expected: String = new('\u{0}', '\u{10FFFF}').collect();
+        for &size in &[4, 7, 1000, 8 * 1024] {
+            let mut iter = new('\u{0}', '\u{10FFFF}');
+            let mut buf = vec![0; size];
+            let mut out = Vec::new();
+            let mut count = 0;
+            loop {
+                let (bytes, chars) = iter.encode_utf8_into(&mut buf);
+                if bytes == 0 {
+                    break
+                }
+                out.extend_from_slice(&buf[..bytes]);
+                count += chars;
+            }
+            assert_eq!(iter.len(), 0);
+            assert_eq!(count, 1_114_112 - 2048);
+            assert!(out == expected.as_bytes());
+        }
+    }
+    #[test]
+    fn encode_utf8_into_small() {
+        let mut iter = new('\u{FFFF}', '\u{10000}');
+        let mut buf = [0; 3];
+        assert_eq!(iter.encode_utf8_into(&mut buf), (3, 1));
+        assert_eq!(iter.encode_utf8_into(&mut buf), (0, 0));
+        assert_eq!(iter.next(), Some('\u{10000}'));
+        assert_eq!(iter.encode_utf8_into(&mut buf), (0, 0));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn utf8_len_segments() {
+        let segments: Vec<_> = new('\u{0}', '\u{10FFFF}').utf8_len_segments().collect();
+        assert_eq!(segments.len(), 4);
+        for (iter, len) in segments {
+            assert!(iter.clone().all(|c| c.len_utf8() == len as usize));
+            assert_eq!(iter.utf8_len(), iter.len() * len as usize);
+        }
+        let segments: Vec<_> = new('\u{D7FF}', '\u{10000}').utf8_len_segments()
+            .map(|(iter, len)| (iter.len(), len))
+            .collect();
+        assert_eq!(segments, [(0x10000 - 0xD7FF - 2048, 3), (1, 4)]);
+
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert_eq!(iter.utf8_len_segments().len(), 0);
+    }
+    #[test]
+    fn ascii_bmp_only() {
+        let mut iter = new('\u{7F}', '\u{80}');
+        assert!(!iter.is_ascii_only());
+        iter.next_back();
+        assert!(iter.is_ascii_only());
+        iter.next();
+        assert!(iter.is_ascii_only() && iter.is_bmp_only());
+
+        let mut iter = new('\u{FFFF}', '\u{10000}');
+        assert!(!iter.is_bmp_only());
+        iter.next_back();
+        assert!(iter.is_bmp_only());
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ranges_ascii_bmp_only() {
+        let ranges = new('\u{0}', '\u{A0}').printable_only();
+        assert!(!ranges.is_ascii_only());
+        let mut ranges = new('\u{0}', '\u{9F}').printable_only();
+        assert!(ranges.is_ascii_only());
+        assert!(ranges.by_ref().all(|c| c.is_ascii()));
+        assert!(ranges.is_ascii_only());
+        assert!(!new('\u{FFFE}', '\u{10000}').skip_noncharacters().is_bmp_only());
+        assert!(new('\u{FFFE}', '\u{FFFF}').skip_noncharacters().is_bmp_only());
+    }
+}
@@ -0,0 +1,234 @@
+//! Escaping characters for inclusion in source code and markup.
+
+use alloc::string::String;
+
+use entities::ENTITIES;
+use Iter;
+use Ranges;
+
+/// An iterator adaptor yielding each character as a Rust character
+/// literal, like `'a'`, `'\n'` or `'\u{301}'`.
+///
+/// The spelling of the escapes is fixed:
+///
+/// - `\0`, `\t`, `\n`, `\r`, `\'` and `\\` use their short escapes,
+/// - other non-printable characters and grapheme extenders (like
+///   combining marks, which would otherwise attach to the opening
+///   quote) use `\u{..}` with lowercase hex digits,
+/// - everything else, including `"`, is written as itself.
+///
+/// Which characters are printable or grapheme extenders follows the
+/// Unicode tables of the standard library, so newly assigned
+/// characters can move from `\u{..}` to themselves when the compiler
+/// updates its Unicode version.
+///
+/// This is constructed by the `rust_literals` method on `Iter` and
+/// `Ranges`.
+pub struct RustLiterals<I> {
+    iter: I,
+}
+
+fn rust_literal(c: char) -> String {
+    let short = match c {
+        '\0' => "\\0",
+        '\t' => "\\t",
+        '\n' => "\\n",
+        '\r' => "\\r",
+        '\'' => "\\'",
+        '\\' => "\\\\",
+        _ => "",
+    };
+    if !short.is_empty() {
+        format!("'{}'", short)
+    } else if c != '"' && c.escape_debug().next() == Some('\\') {
+        // only the classification comes from `escape_debug`, whose
+        // spelling of escapes varies between Rust versions
+        format!("'\\u{{{:x}}}'", c as u32)
+    } else {
+        format!("'{}'", c)
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for RustLiterals<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.iter.next().map(rust_literal)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for RustLiterals<I> {
+    fn next_back(&mut self) -> Option<String> {
+        self.iter.next_back().map(rust_literal)
+    }
+}
+
+impl<I: ExactSizeIterator<Item = char>> ExactSizeIterator for RustLiterals<I> {}
+
+/// An iterator adaptor yielding each character as an HTML/XML
+/// numeric character reference, like `&#x41;`.
+///
+/// This is constructed by the `html_entities` method on `Iter` and
+/// `Ranges`.
+pub struct HtmlEntities<I> {
+    iter: I,
+    named: bool,
+}
+
+impl<I> HtmlEntities<I> {
+    /// Use the HTML named character references (like `&amp;`,
+    /// `&nbsp;` or `&eacute;`) for the characters that have one, and
+    /// numeric references for the rest.
+    ///
+    /// The names are the 252 entities of HTML 4 and XHTML 1.0's
+    /// `&apos;`, which all HTML and XHTML consumers understand. Plain
+    /// XML only predefines `&amp;`, `&lt;`, `&gt;`, `&quot;` and
+    /// `&apos;`.
+    ///
+    /// ```rust
+    /// let v: Vec<String> = char_iter::new('\u{E8}', '\u{EA}').html_entities().named().collect();
+    /// assert_eq!(v, ["&egrave;", "&eacute;", "&ecirc;"]);
+    /// ```
+    pub fn named(self) -> HtmlEntities<I> {
+        HtmlEntities { named: true, ..self }
+    }
+
+    fn entity(&self, c: char) -> String {
+        let name = if self.named {
+            ENTITIES.binary_search_by_key(&c, |&(c, _)| c).ok().map(|i| ENTITIES[i].1)
+        } else {
+            None
+        };
+        match name {
+            Some(name) => format!("&{};", name),
+            None => format!("&#x{:X};", c as u32),
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for HtmlEntities<I> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        self.iter.next().map(|c| self.entity(c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for HtmlEntities<I> {
+    fn next_back(&mut self) -> Option<String> {
+        self.iter.next_back().map(|c| self.entity(c))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = char>> ExactSizeIterator for HtmlEntities<I> {}
+
+impl Iter {
+    /// Write each character as a Rust character literal, for
+    /// generating source code.
+    ///
+    /// ```rust
+    /// let v: Vec<String> = char_iter::new('&', '(').rust_literals().collect();
+    /// assert_eq!(v, ["'&'", "'\\''", "'('"]);
+    /// ```
+    pub fn rust_literals(self) -> RustLiterals<Iter> {
+        RustLiterals { iter: self }
+    }
+
+    /// Write each character as an HTML/XML numeric character
+    /// reference.
+    ///
+    /// ```rust
+    /// let v: Vec<String> = char_iter::new('A', 'B').html_entities().collect();
+    /// assert_eq!(v, ["&#x41;", "&#x42;"]);
+    /// ```
+    pub fn html_entities(self) -> HtmlEntities<Iter> {
+        HtmlEntities { iter: self, named: false }
+    }
+}
+
+impl Ranges {
+    /// Write each character as a Rust character literal, as
+    /// `Iter::rust_literals`.
+    pub fn rust_literals(self) -> RustLiterals<Ranges> {
+        RustLiterals { iter: self }
+    }
+
+    /// Write each character as an HTML/XML numeric character
+    /// reference, as `Iter::html_entities`.
+    pub fn html_entities(self) -> HtmlEntities<Ranges> {
+        HtmlEntities { iter: self, named: false }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::rust_literal;
+    use new;
+
+    #[test]
+    fn rust_literal_rules() {
+        let short: Vec<String> = ['\0', '\t', '\n', '\r', '\'', '\\'].iter()
+            .map(|&c| rust_literal(c))
+            .collect();
+        assert_eq!(short, ["'\\0'", "'\\t'", "'\\n'", "'\\r'", "'\\''", "'\\\\'"]);
+        // non-printable
+        assert_eq!(rust_literal('\u{7F}'), "'\\u{7f}'");
+        assert_eq!(rust_literal('\u{AD}'), "'\\u{ad}'");
+        assert_eq!(rust_literal('\u{E000}'), "'\\u{e000}'");
+        // grapheme extend
+        assert_eq!(rust_literal('\u{301}'), "'\\u{301}'");
+        assert_eq!(rust_literal('\u{200D}'), "'\\u{200d}'");
+        // written as itself
+        assert_eq!(rust_literal('"'), "'\"'");
+        assert_eq!(rust_literal(' '), "' '");
+        assert_eq!(rust_literal('é'), "'é'");
+        assert_eq!(rust_literal('\u{1F600}'), "'\u{1F600}'");
+    }
+    #[test]
+    fn rust_literals() {
+        let v: Vec<String> = new('\u{0}', '\u{2}').rust_literals().collect();
+        assert_eq!(v, ["'\\0'", "'\\u{1}'", "'\\u{2}'"]);
+        let v: Vec<String> = new('\t', '\n').rust_literals().rev().collect();
+        assert_eq!(v, ["'\\n'", "'\\t'"]);
+        assert_eq!(new('\\', '\\').rust_literals().next().unwrap(), "'\\\\'");
+        assert_eq!(new('é', 'é').rust_literals().next().unwrap(), "'é'");
+        assert_eq!(new('\u{301}', '\u{301}').rust_literals().next().unwrap(), "'\\u{301}'");
+    }
+    #[test]
+    fn html_entities() {
+        let v: Vec<String> = new('\u{FFFF}', '\u{10000}').html_entities().rev().collect();
+        assert_eq!(v, ["&#x10000;", "&#xFFFF;"]);
+        let v: Vec<String> = new('!', '"').html_entities().collect();
+        assert_eq!(v, ["&#x21;", "&#x22;"]);
+        let v: Vec<String> = new('<', '>').html_entities().named().collect();
+        assert_eq!(v, ["&lt;", "&#x3D;", "&gt;"]);
+        let v: Vec<String> = new('%', '\'').html_entities().named().rev().collect();
+        assert_eq!(v, ["&apos;", "&amp;", "&#x25;"]);
+        assert_eq!(new('"', '"').html_entities().named().next().unwrap(), "&quot;");
+        assert_eq!(new('\u{A0}', '\u{A0}').html_entities().named().next().unwrap(), "&nbsp;");
+        // digits have no names
+        let v: Vec<String> = new('0', '1').html_entities().named().collect();
+        assert_eq!(v, ["&#x30;", "&#x31;"]);
+    }
+    #[test]
+    fn entity_table() {
+        use entities::ENTITIES;
+        for (a, b) in ENTITIES.iter().zip(&ENTITIES[1..]) {
+            assert!(a.0 < b.0);
+        }
+        assert_eq!(ENTITIES.len(), 253);
+    }
+    #[test]
+    fn ranges() {
+        let v: Vec<String> = new('\u{FFFF}', '\u{10000}').skip_noncharacters().rust_literals().collect();
+        assert_eq!(v, ["'𐀀'"]);
+        let v: Vec<String> = new('\u{E000}', '\u{E001}').skip_private_use().html_entities().collect();
+        assert!(v.is_empty());
+    }
+}
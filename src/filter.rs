@@ -0,0 +1,167 @@
+//! Filtering out special classes of code points.
+
+use Iter;
+use Ranges;
+
+pub(crate) static NONCHARACTERS: &[(char, char)] = &[
+    ('\u{FDD0}', '\u{FDEF}'),
+    ('\u{FFFE}', '\u{FFFF}'),
+    ('\u{1FFFE}', '\u{1FFFF}'),
+    ('\u{2FFFE}', '\u{2FFFF}'),
+    ('\u{3FFFE}', '\u{3FFFF}'),
+    ('\u{4FFFE}', '\u{4FFFF}'),
+    ('\u{5FFFE}', '\u{5FFFF}'),
+    ('\u{6FFFE}', '\u{6FFFF}'),
+    ('\u{7FFFE}', '\u{7FFFF}'),
+    ('\u{8FFFE}', '\u{8FFFF}'),
+    ('\u{9FFFE}', '\u{9FFFF}'),
+    ('\u{AFFFE}', '\u{AFFFF}'),
+    ('\u{BFFFE}', '\u{BFFFF}'),
+    ('\u{CFFFE}', '\u{CFFFF}'),
+    ('\u{DFFFE}', '\u{DFFFF}'),
+    ('\u{EFFFE}', '\u{EFFFF}'),
+    ('\u{FFFFE}', '\u{FFFFF}'),
+    ('\u{10FFFE}', '\u{10FFFF}'),
+];
+
+pub(crate) static CONTROLS: &[(char, char)] = &[
+    ('\u{0}', '\u{1F}'),
+    ('\u{7F}', '\u{9F}'),
+];
+
+pub(crate) static PRIVATE_USE: &[(char, char)] = &[
+    ('\u{E000}', '\u{F8FF}'),
+    ('\u{F0000}', '\u{FFFFD}'),
+    ('\u{100000}', '\u{10FFFD}'),
+];
+
+impl Iter {
+    /// Skip the 66 noncharacters: `U+FDD0`-`U+FDEF`, and the last
+    /// two code points of each plane (`U+FFFE`, `U+FFFF`, `U+1FFFE`,
+    /// ..., `U+10FFFF`).
+    ///
+    /// ```rust
+    /// let v: Vec<char> = char_iter::new('\u{FFFD}', '\u{10000}').skip_noncharacters().collect();
+    /// assert_eq!(v, &['\u{FFFD}', '\u{10000}']);
+    /// ```
+    pub fn skip_noncharacters(self) -> Ranges {
+        Ranges::from(self).skip_noncharacters()
+    }
+
+    /// Skip the three Private Use Areas: `U+E000`-`U+F8FF`,
+    /// `U+F0000`-`U+FFFFD` and `U+100000`-`U+10FFFD`.
+    ///
+    /// ```rust
+    /// let v: Vec<char> = char_iter::new('\u{D7FF}', '\u{F900}').skip_private_use().collect();
+    /// assert_eq!(v, &['\u{D7FF}', '\u{F900}']);
+    /// ```
+    pub fn skip_private_use(self) -> Ranges {
+        Ranges::from(self).skip_private_use()
+    }
+
+    /// Skip the C0 and C1 control characters (`U+0000`-`U+001F`,
+    /// `U+007F`-`U+009F`), which are exactly the `Cc` general
+    /// category.
+    ///
+    /// ```rust
+    /// let s: String = char_iter::new('\u{0}', '\u{7F}').printable_only().collect();
+    /// assert_eq!(s.len(), 95);
+    /// assert!(s.starts_with(" !\"#"));
+    /// assert!(s.ends_with("}~"));
+    /// ```
+    pub fn printable_only(self) -> Ranges {
+        Ranges::from(self).printable_only()
+    }
+
+    /// Keep only the characters in the three Private Use Areas.
+    ///
+    /// ```rust
+    /// let iter = char_iter::new('\u{0}', '\u{10FFFF}').private_use_only();
+    /// assert_eq!(iter.len(), 6400 + 65534 + 65534);
+    /// ```
+    pub fn private_use_only(self) -> Ranges {
+        Ranges::from(self).private_use_only()
+    }
+}
+
+impl Ranges {
+    /// Skip the 66 noncharacters, as `Iter::skip_noncharacters`.
+    pub fn skip_noncharacters(self) -> Ranges {
+        self.exclude(NONCHARACTERS)
+    }
+
+    /// Skip the control characters, as `Iter::printable_only`.
+    pub fn printable_only(self) -> Ranges {
+        self.exclude(CONTROLS)
+    }
+
+    /// Skip the three Private Use Areas, as `Iter::skip_private_use`.
+    pub fn skip_private_use(self) -> Ranges {
+        self.exclude(PRIVATE_USE)
+    }
+
+    /// Keep only the characters in the three Private Use Areas, as
+    /// `Iter::private_use_only`.
+    pub fn private_use_only(self) -> Ranges {
+        self.restrict(PRIVATE_USE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn noncharacters() {
+        let iter = new('\u{0}', '\u{10FFFF}');
+        let len = iter.len();
+        let iter = iter.skip_noncharacters();
+        assert_eq!(iter.len(), len - 66);
+        let nonchar = |c: char| (c as u32).wrapping_sub(0xFDD0) < 0x20 || c as u32 & 0xFFFE == 0xFFFE;
+        assert_eq!(iter.filter(|&c| nonchar(c)).count(), 0);
+    }
+    #[test]
+    fn noncharacters_rev() {
+        let v: Vec<char> = new('\u{10FFFD}', '\u{10FFFF}').skip_noncharacters().rev().collect();
+        assert_eq!(v, &['\u{10FFFD}']);
+        let v: Vec<char> = new('\u{FDCF}', '\u{FDF0}').skip_noncharacters().rev().collect();
+        assert_eq!(v, &['\u{FDF0}', '\u{FDCF}']);
+    }
+
+    #[test]
+    fn private_use() {
+        let v: Vec<char> = new('\u{D7FF}', '\u{E001}').private_use_only().collect();
+        assert_eq!(v, &['\u{E000}', '\u{E001}']);
+        let v: Vec<char> = new('\u{D7FF}', '\u{E001}').skip_private_use().collect();
+        assert_eq!(v, &['\u{D7FF}']);
+    }
+    #[test]
+    fn private_use_partition() {
+        let len = new('\u{0}', '\u{10FFFF}').len();
+        let inside = new('\u{0}', '\u{10FFFF}').private_use_only().len();
+        let outside = new('\u{0}', '\u{10FFFF}').skip_private_use().len();
+        assert_eq!(inside + outside, len);
+        // the noncharacters at the end of planes 15 and 16 aren't private use
+        let v: Vec<char> = new('\u{10FFFC}', '\u{10FFFF}').skip_private_use().collect();
+        assert_eq!(v, &['\u{10FFFE}', '\u{10FFFF}']);
+    }
+
+    #[test]
+    fn printable() {
+        let iter = new('\u{0}', '\u{10FFFF}').printable_only();
+        assert_eq!(iter.len(), new('\u{0}', '\u{10FFFF}').len() - 65);
+        let v: Vec<char> = new('~', '\u{A1}').printable_only().rev().collect();
+        assert_eq!(v, &['\u{A1}', '\u{A0}', '~']);
+    }
+    #[test]
+    fn combined() {
+        let v: Vec<char> = new('\u{0}', '\u{10FFFF}')
+            .printable_only()
+            .skip_noncharacters()
+            .skip_private_use()
+            .rev()
+            .take(2)
+            .collect();
+        assert_eq!(v, &['\u{EFFFD}', '\u{EFFFC}']);
+    }
+}
@@ -0,0 +1,388 @@
+//! Streaming ranges of characters, and translations of them, as
+//! UTF-8 bytes.
+
+use std::io::{self, BufRead, Read, Write};
+use std::str;
+
+use CharMapping;
+use Iter;
+
+/// The size of the buffer used to batch up writes.
+const CHUNK: usize = 8 * 1024;
+
+impl Iter {
+    /// Write the UTF-8 encoding of the remaining characters into `w`.
+    ///
+    /// The bytes are batched into 8KB chunks by `encode_utf8_into`,
+    /// so this doesn't need `w` to be buffered.
+    ///
+    /// ```rust
+    /// let mut out = Vec::new();
+    /// char_iter::new('a', 'c').write_io(&mut out).unwrap();
+    /// assert_eq!(out, b"abc");
+    /// ```
+    pub fn write_io<W: Write + ?Sized>(mut self, w: &mut W) -> io::Result<()> {
+        let mut buf = [0; CHUNK];
+        loop {
+            let (n, _) = self.encode_utf8_into(&mut buf);
+            if n == 0 {
+                return Ok(())
+            }
+            w.write_all(&buf[..n])?;
+        }
+    }
+
+    /// Read the UTF-8 encoding of the remaining characters, encoding
+    /// them on demand.
+    ///
+    /// ```rust
+    /// use std::io::Read;
+    ///
+    /// let mut s = String::new();
+    /// char_iter::new('x', 'z').into_reader().read_to_string(&mut s).unwrap();
+    /// assert_eq!(s, "xyz");
+    /// ```
+    pub fn into_reader(self) -> Reader {
+        Reader { iter: self, buf: Vec::with_capacity(CHUNK), pos: 0 }
+    }
+}
+
+/// An `io::Read` and `io::BufRead` adaptor producing the UTF-8
+/// encoding of a range of characters.
+///
+/// This is constructed by the `into_reader` method on `Iter`.
+pub struct Reader {
+    iter: Iter,
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl Read for Reader {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        let n = {
+            let buf = self.fill_buf()?;
+            let n = buf.len().min(out.len());
+            out[..n].copy_from_slice(&buf[..n]);
+            n
+        };
+        self.consume(n);
+        Ok(n)
+    }
+}
+
+impl BufRead for Reader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos == self.buf.len() {
+            self.buf.clear();
+            self.pos = 0;
+            let mut bytes = [0; 4];
+            while self.buf.len() + 4 <= CHUNK {
+                match self.iter.next() {
+                    Some(c) => self.buf.extend_from_slice(c.encode_utf8(&mut bytes).as_bytes()),
+                    None => break,
+                }
+            }
+        }
+        Ok(&self.buf[self.pos..])
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.pos = (self.pos + amt).min(self.buf.len());
+    }
+}
+
+impl CharMapping {
+    /// Wrap `w` so that the UTF-8 text written through it is
+    /// translated by this mapping on the way.
+    ///
+    /// Characters split across writes are handled, but bytes that
+    /// aren't valid UTF-8 cause an `InvalidData` error, once the text
+    /// before them has been written.
+    ///
+    /// ```rust
+    /// use std::io::Write;
+    /// use char_iter::{new, CharMapping};
+    ///
+    /// let mut upper = CharMapping::new();
+    /// upper.insert(new('a', 'z'), new('A', 'Z'));
+    /// let mut w = upper.wrap_writer(Vec::new());
+    /// w.write_all(b"hello, world").unwrap();
+    /// assert_eq!(w.into_inner(), b"HELLO, WORLD");
+    /// ```
+    pub fn wrap_writer<W: Write>(&self, w: W) -> TranslatingWriter<'_, W> {
+        TranslatingWriter { mapping: self, inner: w, pending: Vec::new() }
+    }
+
+    /// Wrap `r` so that the UTF-8 text read from it is translated by
+    /// this mapping, a chunk at a time.
+    ///
+    /// Bytes that aren't valid UTF-8 (including a character cut off
+    /// by the end of the input) cause an `InvalidData` error.
+    ///
+    /// ```rust
+    /// use std::io::Read;
+    /// use char_iter::{new, CharMapping};
+    ///
+    /// let mut upper = CharMapping::new();
+    /// upper.insert(new('a', 'z'), new('A', 'Z'));
+    /// let mut s = String::new();
+    /// upper.wrap_reader(&b"hello, world"[..]).read_to_string(&mut s).unwrap();
+    /// assert_eq!(s, "HELLO, WORLD");
+    /// ```
+    pub fn wrap_reader<R: Read>(&self, r: R) -> TranslatingReader<'_, R> {
+        TranslatingReader {
+            mapping: self,
+            inner: r,
+            pending: Vec::new(),
+            buf: Vec::new(),
+            pos: 0,
+        }
+    }
+
+    /// Translate the complete characters at the start of `bytes`
+    /// into `out`, returning how many bytes were used. Only an
+    /// incomplete character at the very end is left over.
+    fn translate_utf8(&self, bytes: &[u8], out: &mut Vec<u8>) -> io::Result<usize> {
+        let valid = match str::from_utf8(bytes) {
+            Ok(s) => s,
+            Err(e) => {
+                if e.error_len().is_some() {
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, e))
+                }
+                // the rest is the start of a multi-byte character.
+                str::from_utf8(&bytes[..e.valid_up_to()]).unwrap()
+            }
+        };
+        let mut buf = [0; 4];
+        for c in valid.chars() {
+            out.extend_from_slice(self.translate(c).encode_utf8(&mut buf).as_bytes());
+        }
+        Ok(valid.len())
+    }
+}
+
+/// An `io::Write` adaptor translating UTF-8 text with a
+/// `CharMapping`.
+///
+/// This is constructed by the `wrap_writer` method on `CharMapping`.
+pub struct TranslatingWriter<'a, W> {
+    mapping: &'a CharMapping,
+    inner: W,
+    // the start of a character split across writes.
+    pending: Vec<u8>,
+}
+
+impl<'a, W> TranslatingWriter<'a, W> {
+    /// Unwrap the underlying writer.
+    ///
+    /// Any partial character that was written is discarded.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+}
+
+impl<'a, W: Write> Write for TranslatingWriter<'a, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut bytes = Vec::with_capacity(self.pending.len() + buf.len());
+        bytes.extend_from_slice(&self.pending);
+        bytes.extend_from_slice(buf);
+        let (valid, invalid) = match str::from_utf8(&bytes) {
+            Ok(_) => (bytes.len(), None),
+            Err(e) => (e.valid_up_to(), e.error_len().map(|_| e)),
+        };
+        // invalid bytes make this a short write of the text before
+        // them, so that the error comes from the next call. Errors
+        // leave `pending` alone, since none of `buf` was used.
+        let used = match invalid {
+            Some(e) if valid <= self.pending.len() => {
+                return Err(io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Some(_) => valid - self.pending.len(),
+            None => buf.len(),
+        };
+        let mut out = Vec::with_capacity(valid);
+        self.mapping.translate_utf8(&bytes[..valid], &mut out)?;
+        self.inner.write_all(&out)?;
+        self.pending = if invalid.is_some() { Vec::new() } else { bytes.split_off(valid) };
+        Ok(used)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// An `io::Read` adaptor translating UTF-8 text with a
+/// `CharMapping`.
+///
+/// This is constructed by the `wrap_reader` method on `CharMapping`.
+pub struct TranslatingReader<'a, R> {
+    mapping: &'a CharMapping,
+    inner: R,
+    // bytes read but not yet translated.
+    pending: Vec<u8>,
+    // translated bytes not yet returned, from `pos`.
+    buf: Vec<u8>,
+    pos: usize,
+}
+
+impl<'a, R: Read> Read for TranslatingReader<'a, R> {
+    fn read(&mut self, out: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.buf.len() {
+            self.buf.clear();
+            self.pos = 0;
+            let start = self.pending.len();
+            self.pending.resize(start + CHUNK, 0);
+            let n = match self.inner.read(&mut self.pending[start..]) {
+                Ok(n) => n,
+                Err(e) => {
+                    self.pending.truncate(start);
+                    return Err(e)
+                }
+            };
+            self.pending.truncate(start + n);
+            if n == 0 {
+                if self.pending.is_empty() {
+                    return Ok(0)
+                }
+                return Err(io::Error::new(io::ErrorKind::InvalidData,
+                                          "stream ended in the middle of a character"))
+            }
+            let used = self.mapping.translate_utf8(&self.pending, &mut self.buf)?;
+            self.pending.drain(..used);
+        }
+        let n = (self.buf.len() - self.pos).min(out.len());
+        out[..n].copy_from_slice(&self.buf[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{self, BufRead, Read, Write};
+    use new;
+    use CharMapping;
+
+    fn greek() -> CharMapping {
+        let mut m = CharMapping::new();
+        m.insert(new('a', 'b'), new('α', 'β'));
+        m.insert(new('é', 'é'), new('😀', '😀'));
+        m
+    }
+
+    #[test]
+    fn write_io() {
+        let mut out = Vec::new();
+        new('\u{0}', '\u{10FFFF}').write_io(&mut out).unwrap();
+        assert_eq!(out, new('\u{0}', '\u{10FFFF}').collect::<String>().into_bytes());
+    }
+    #[test]
+    fn write_io_chunked() {
+        struct Counter(Vec<usize>);
+        impl Write for Counter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.push(buf.len());
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> { Ok(()) }
+        }
+        let mut w = Counter(vec![]);
+        new('\u{10000}', '\u{10FFFF}').write_io(&mut w).unwrap();
+        assert!(w.0.len() > 1);
+        assert!(w.0.iter().all(|&n| n <= super::CHUNK));
+        assert_eq!(w.0.iter().sum::<usize>(), 4 * 0x100000);
+    }
+    #[test]
+    fn into_reader() {
+        let mut out = Vec::new();
+        new('\u{0}', '\u{10FFFF}').into_reader().read_to_end(&mut out).unwrap();
+        assert_eq!(out, new('\u{0}', '\u{10FFFF}').collect::<String>().into_bytes());
+    }
+    #[test]
+    fn into_reader_small_reads() {
+        let mut reader = new('\u{FF}', '\u{101}').into_reader();
+        let mut buf = [0; 3];
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf, b"\xC3\xBF\xC4");
+        assert_eq!(reader.read(&mut buf).unwrap(), 3);
+        assert_eq!(&buf, b"\x80\xC4\x81");
+        assert_eq!(reader.read(&mut buf).unwrap(), 0);
+    }
+    #[test]
+    fn into_reader_lines() {
+        let lines: Vec<String> = new('\n', '\r').into_reader().lines().map(|l| l.unwrap()).collect();
+        assert_eq!(lines, ["", "\u{B}\u{C}\r"]);
+    }
+    #[test]
+    fn wrap_writer() {
+        let m = greek();
+        let text = "abcé!a".as_bytes();
+        for split in 0..text.len() + 1 {
+            let mut w = m.wrap_writer(Vec::new());
+            w.write_all(&text[..split]).unwrap();
+            w.write_all(&text[split..]).unwrap();
+            assert_eq!(String::from_utf8(w.into_inner()).unwrap(), "αβc😀!α");
+        }
+        let mut w = m.wrap_writer(Vec::new());
+        let e = w.write_all(b"a\xFFb").unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(w.into_inner(), "α".as_bytes());
+    }
+    #[test]
+    fn wrap_writer_errors() {
+        // fails the first write after `ok` successful ones
+        struct Flaky { out: Vec<u8>, ok: usize }
+        impl Write for Flaky {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                if self.ok == 0 {
+                    self.ok = usize::MAX;
+                    return Err(io::Error::other("flaky"))
+                }
+                self.ok -= 1;
+                self.out.extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> { Ok(()) }
+        }
+        let m = greek();
+        let mut w = m.wrap_writer(Flaky { out: Vec::new(), ok: 1 });
+        // "é" split, and then a failed write of its second half
+        w.write_all(b"b\xC3").unwrap();
+        assert!(w.write(b"\xA9").is_err());
+        w.write_all(b"\xA9").unwrap();
+        assert_eq!(String::from_utf8(w.into_inner().out).unwrap(), "β😀");
+
+        // bytes that cannot continue a split character are refused,
+        // and it can still be finished
+        let mut w = m.wrap_writer(Vec::new());
+        w.write_all(b"\xC3").unwrap();
+        assert!(w.write(b"a").is_err());
+        assert!(w.write(b"\xA9").is_ok());
+    }
+    #[test]
+    fn wrap_reader() {
+        struct OneByte<'a>(&'a [u8]);
+        impl<'a> Read for OneByte<'a> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                match self.0.split_first() {
+                    Some((&b, rest)) if !buf.is_empty() => {
+                        buf[0] = b;
+                        self.0 = rest;
+                        Ok(1)
+                    }
+                    _ => Ok(0),
+                }
+            }
+        }
+        let m = greek();
+        let mut s = String::new();
+        m.wrap_reader(OneByte("abcé!a".as_bytes())).read_to_string(&mut s).unwrap();
+        assert_eq!(s, "αβc😀!α");
+
+        let mut s = String::new();
+        assert!(m.wrap_reader(&b"a\xC3"[..]).read_to_string(&mut s).is_err());
+        let mut s = String::new();
+        assert!(m.wrap_reader(&b"\xFFa"[..]).read_to_string(&mut s).is_err());
+    }
+}
@@ -17,6 +17,51 @@
 //! char-iter = "0.1"
 //! ```
 //!
+//! The crate works without the standard library: disable the default
+//! `std` feature for `#![no_std]`. The `alloc` feature brings back
+//! the helpers that return a `Vec`, a `String` or a `Ranges`. Only
+//! `std` adds the `io` helpers and the Unicode data integrations.
+//!
+//! The `safe` feature checks every conversion that would otherwise
+//! be unchecked, and forbids `unsafe` code.
+//!
+//! # Rust versions
+//!
+//! The default features, and the `no_std` builds, need Rust 1.60.
+//! Some optional features need a newer compiler, mostly for their
+//! dependencies:
+//!
+//! - `rand`, `names` and `cli`: 1.63
+//! - `unicode-width`: 1.66
+//! - `safe`: 1.67, since `char_array!` then uses `char::from_u32` in
+//!   a constant
+//! - `rayon`: 1.80
+//! - `unicode-data` and `icu`: 1.88
+//!
+//! Running the tests and benchmarks needs 1.88, for the development
+//! dependencies.
+//!
+//! # Unicode versions
+//!
+//! The Unicode data comes from several places, which do not all
+//! follow the same version of the standard:
+//!
+//! - general categories, scripts, binary properties, East Asian
+//!   Width, Bidi_Class and canonical combining classes
+//!   (`unicode-data`): `icu_properties` 2.3, Unicode 17.0
+//! - `UnicodeVersion` (the Age property) and case folding
+//!   (`unicode-data`): `regex-syntax` 0.8, Unicode 16.0
+//! - `Block` and `Script` names: tables in this crate, Unicode 17.0
+//! - character names (`names`): `unicode_names2` 1.3, Unicode 16.0
+//! - display widths (`unicode-width`): `unicode-width` 0.2, Unicode 17.0
+//! - case mapping and `char` predicates: the standard library, so the
+//!   Unicode version of the compiler
+//!
+//! So a character new in Unicode 17.0 has a category and a script,
+//! but no age and no name. `Cargo.toml` pins the minor versions of
+//! `icu_properties` and `unicode_names2`, which ship their data
+//! inline.
+//!
 //! # Examples
 //!
 //! ```rust
@@ -40,11 +85,131 @@
 //! assert_eq!(v, &['\u{D7FF}', '\u{E000}']);
 //! ```
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(feature = "safe", forbid(unsafe_code))]
+#![cfg_attr(feature = "unstable", feature(trusted_len))]
 #![cfg_attr(all(test, feature = "unstable"), feature(test))]
 
+#[cfg(feature = "alloc")]
+#[macro_use]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate core;
+#[cfg(feature = "futures")]
+extern crate futures_core;
+#[cfg(all(test, feature = "futures"))]
+extern crate futures_task;
+#[cfg(feature = "icu")]
+extern crate icu_collator;
+#[cfg(all(test, feature = "icu"))]
+extern crate icu_locale_core;
+#[cfg(feature = "unicode-data")]
+extern crate icu_properties;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "unicode-data")]
+extern crate regex_syntax;
+#[cfg(feature = "unic-char-range")]
+extern crate unic_char_range;
+#[cfg(feature = "names")]
+extern crate unicode_names2;
+#[cfg(feature = "confusables")]
+extern crate unicode_security;
+#[cfg(feature = "unicode-width")]
+extern crate unicode_width;
+
+#[doc(hidden)]
+pub use array::{__char_array, __char_array_len};
+#[cfg(feature = "unicode-data")]
+pub use block::Blocks;
+pub use case::{CasePairs, ToLowercase, ToUppercase};
+pub use chunks::Chunks;
+pub use coalesce::{coalesce, Coalesce};
+#[cfg(feature = "alloc")]
+pub use coalesce::coalesce_unsorted;
+#[cfg(feature = "confusables")]
+pub use confusables::confusable_skeleton;
+pub use counted::FilterCounted;
+pub use display::Display;
+pub use encode::{Utf16Units, Utf8Bytes};
+#[cfg(feature = "alloc")]
+pub use escape::{HtmlEntities, RustLiterals};
+#[cfg(feature = "std")]
+pub use io::{Reader, TranslatingReader, TranslatingWriter};
+#[cfg(feature = "alloc")]
+pub use mapping::CharMapping;
+#[cfg(feature = "names")]
+pub use names::Names;
+#[cfg(feature = "rayon")]
+pub use par::ParIter;
+#[cfg(feature = "unicode-data")]
+pub use props::{BidiClass, EastAsianWidth};
+#[cfg(feature = "alloc")]
+pub use ranges::Ranges;
+pub use raw::{ascii, codepoints, codepoints_with_surrogates, Ascii, Codepoints};
+pub use shuffle::Shuffled;
+pub use stepping::{distance, next_char, offset, prev_char};
+#[cfg(feature = "unicode-data")]
+pub use tables::{Block, Script};
+#[cfg(feature = "unicode-data")]
+pub use unicode::{fold_orbit, Category, Property, UnicodeVersion};
+#[cfg(feature = "unicode-width")]
+pub use width::FilterWidth;
+
+#[macro_use]
+mod array;
+#[cfg(feature = "unicode-data")]
+mod block;
+mod case;
+mod chunks;
+mod coalesce;
+#[cfg(feature = "icu")]
+mod collation;
+mod compare;
+#[cfg(feature = "confusables")]
+mod confusables;
+mod counted;
+mod display;
+mod encode;
+#[cfg(feature = "alloc")]
+mod entities;
+#[cfg(feature = "alloc")]
+mod escape;
+#[cfg(feature = "alloc")]
+mod filter;
+#[cfg(feature = "std")]
+mod io;
+mod mapping;
+#[cfg(feature = "names")]
+mod names;
+#[cfg(feature = "rayon")]
+mod par;
+#[cfg(feature = "unicode-data")]
+mod props;
+#[cfg(all(feature = "rand", feature = "alloc"))]
+mod random;
+#[cfg(feature = "alloc")]
+mod ranges;
+mod raw;
+mod shuffle;
+mod stepping;
+#[cfg(feature = "futures")]
+mod stream;
+#[cfg(feature = "unicode-data")]
+mod tables;
+#[cfg(feature = "unic-char-range")]
+mod unic;
+#[cfg(feature = "unicode-data")]
+mod unicode;
+#[cfg(feature = "unicode-width")]
+mod width;
+
 /// An iterator over a linear range of characters.
 ///
 /// This is constructed by the `new` function at the top level.
+#[derive(Clone)]
 pub struct Iter {
     start: char,
     end: char,
@@ -60,8 +225,8 @@ pub struct Iter {
 pub fn new(start: char, end: char) -> Iter {
     assert!(start <= end);
     Iter {
-        start: start,
-        end: end,
+        start,
+        end,
         finished: false
     }
 }
@@ -80,8 +245,55 @@ fn step(c: char, d: Dir) -> char {
         Dir::Forward => if val == BEFORE_SUR {AFTER_SUR} else {val + 1},
         Dir::Backward => if val == AFTER_SUR {BEFORE_SUR} else {val - 1},
     };
-    debug_assert!(std::char::from_u32(new_val).is_some());
-    unsafe {std::mem::transmute(new_val)}
+    scalar(new_val)
+}
+
+/// The character `n` scalar values after `c`, which must exist.
+fn forward(c: char, n: u32) -> char {
+    let val = c as u32 + n;
+    let new_val = if c as u32 <= BEFORE_SUR && val >= SUR_START {
+        val + (SUR_END - SUR_START + 1)
+    } else {
+        val
+    };
+    scalar(new_val)
+}
+
+/// Convert `val` to a `char`.
+///
+/// Callers must only pass Unicode scalar values: at most `0x10FFFF`
+/// and outside the surrogate range. `step` and `forward` guarantee
+/// this by jumping over the surrogates, and by never moving past the
+/// end of a range, whose endpoints are themselves `char`s. Without
+/// the `safe` feature this is only checked in debug builds.
+#[inline(always)]
+fn scalar(val: u32) -> char {
+    debug_assert!(core::char::from_u32(val).is_some());
+    #[cfg(not(feature = "safe"))]
+    unsafe {core::char::from_u32_unchecked(val)}
+    #[cfg(feature = "safe")]
+    core::char::from_u32(val).unwrap()
+}
+
+/// View `bytes`, which must be valid UTF-8 (e.g. made by
+/// `char::encode_utf8`), as a `str`, checking only with the `safe`
+/// feature.
+#[inline(always)]
+fn utf8(bytes: &[u8]) -> &str {
+    #[cfg(not(feature = "safe"))]
+    unsafe {core::str::from_utf8_unchecked(bytes)}
+    #[cfg(feature = "safe")]
+    core::str::from_utf8(bytes).unwrap()
+}
+
+/// Convert `bytes`, which must be valid UTF-8, to a `String`,
+/// checking only with the `safe` feature.
+#[cfg(feature = "alloc")]
+fn utf8_string(bytes: alloc::vec::Vec<u8>) -> alloc::string::String {
+    #[cfg(not(feature = "safe"))]
+    unsafe {alloc::string::String::from_utf8_unchecked(bytes)}
+    #[cfg(feature = "safe")]
+    alloc::string::String::from_utf8(bytes).unwrap()
 }
 
 impl Iterator for Iter {
@@ -133,6 +345,27 @@ impl DoubleEndedIterator for Iter {
 
 impl ExactSizeIterator for Iter {}
 
+/// An iterator over the characters of `range`, which is empty if
+/// `range` is.
+///
+/// ```rust
+/// let v: String = char_iter::Iter::from('x'..='z').collect();
+/// assert_eq!(v, "xyz");
+/// ```
+impl From<core::ops::RangeInclusive<char>> for Iter {
+    fn from(range: core::ops::RangeInclusive<char>) -> Iter {
+        Iter {
+            start: *range.start(),
+            end: *range.end(),
+            finished: range.is_empty(),
+        }
+    }
+}
+
+// `size_hint` is always exact.
+#[cfg(all(feature = "unstable", not(feature = "safe")))]
+unsafe impl core::iter::TrustedLen for Iter {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -143,6 +376,16 @@ mod tests {
         assert_eq!(v, &['a', 'b', 'c', 'd', 'e', 'f']);
     }
     #[test]
+    fn from_range_inclusive() {
+        assert_eq!(Iter::from('\u{D7FF}'..='\u{E000}').len(), 2);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = Iter::from('b'..='a');
+        assert_eq!(empty.count(), 0);
+        let mut range = 'a'..='a';
+        range.next();
+        assert_eq!(Iter::from(range).count(), 0);
+    }
+    #[test]
     fn smoke_rev() {
         let v: Vec<char> = new('a', 'f').rev().collect();
         assert_eq!(v, &['f', 'e', 'd', 'c', 'b', 'a']);
@@ -271,4 +514,23 @@ mod benches {
         // close enough.
         b.iter(|| (0..0x10FFFF + 1).count())
     }
+    #[cfg(feature = "alloc")]
+    #[bench]
+    fn to_vec(b: &mut test::Bencher) {
+        b.iter(|| new('\u{0}', '\u{10FFFF}').to_vec())
+    }
+    #[bench]
+    fn collect_vec(b: &mut test::Bencher) {
+        b.iter(|| new('\u{0}', '\u{10FFFF}').collect::<Vec<_>>())
+    }
+    #[cfg(feature = "alloc")]
+    #[bench]
+    fn collect_string(b: &mut test::Bencher) {
+        b.iter(|| new('\u{0}', '\u{10FFFF}').collect_string())
+    }
+    #[cfg(feature = "alloc")]
+    #[bench]
+    fn collect_string_baseline(b: &mut test::Bencher) {
+        b.iter(|| new('\u{0}', '\u{10FFFF}').collect::<String>())
+    }
 }
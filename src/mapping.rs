@@ -0,0 +1,255 @@
+//! Mapping characters in one range onto another.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::iter::Zip;
+
+#[cfg(feature = "alloc")]
+use {new, next_char, prev_char};
+use {distance, offset, Iter};
+
+impl Iter {
+    /// Pair each remaining character with the one in the same
+    /// position of `other`.
+    ///
+    /// Unlike `zip`, this checks that the two ranges have the same
+    /// length first, so a mismatch can't silently drop characters.
+    ///
+    /// ```rust
+    /// let mut pairs = char_iter::new('a', 'z').map_onto(char_iter::new('A', 'Z'));
+    /// assert_eq!(pairs.next(), Some(('a', 'A')));
+    /// assert_eq!(pairs.next_back(), Some(('z', 'Z')));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This panics if `self` and `other` have different lengths.
+    pub fn map_onto(self, other: Iter) -> Zip<Iter, Iter> {
+        assert_eq!(self.len(), other.len(), "ranges have different lengths");
+        self.zip(other)
+    }
+
+    /// The character `n` positions after `c` within the remaining
+    /// characters, wrapping around at the end, like a Caesar cipher.
+    /// Negative `n` moves earlier. This is `None` if `c` isn't one of
+    /// the remaining characters.
+    ///
+    /// ```rust
+    /// let letters = char_iter::new('a', 'z');
+    /// assert_eq!(letters.rotate('a', 13), Some('n'));
+    /// assert_eq!(letters.rotate('y', 3), Some('b'));
+    /// assert_eq!(letters.rotate('a', -1), Some('z'));
+    /// assert_eq!(letters.rotate('!', 1), None);
+    /// ```
+    pub fn rotate(&self, c: char, n: i64) -> Option<char> {
+        if self.finished || c < self.start || self.end < c {
+            return None
+        }
+        let len = self.len() as i64;
+        let pos = distance(self.start, c) as i64;
+        offset(self.start, ((pos + n.rem_euclid(len)) % len) as i32)
+    }
+}
+
+/// A translation table mapping ranges of characters onto other
+/// ranges, like the two sets given to Unix `tr`.
+///
+/// This stores one entry per range, rather than one per character,
+/// and looks characters up by binary search.
+///
+/// ```rust
+/// use char_iter::{new, CharMapping};
+///
+/// let mut rot13 = CharMapping::new();
+/// rot13.insert(new('a', 'm'), new('n', 'z'));
+/// rot13.insert(new('n', 'z'), new('a', 'm'));
+/// assert_eq!(rot13.lookup('a'), Some('n'));
+/// assert_eq!(rot13.lookup('!'), None);
+/// let s: String = "uryyb!".chars().map(|c| rot13.translate(c)).collect();
+/// assert_eq!(s, "hello!");
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct CharMapping {
+    // sorted and disjoint `(start, end, target)`, with `start..=end`
+    // mapping onto the range of the same length starting at `target`.
+    entries: Vec<(char, char, char)>,
+}
+
+#[cfg(feature = "alloc")]
+impl CharMapping {
+    /// Create an empty mapping.
+    pub fn new() -> CharMapping {
+        CharMapping { entries: Vec::new() }
+    }
+
+    /// Create a mapping that moves each character of `range` `n`
+    /// positions later within it, as `Iter::rotate`, but for every
+    /// character at once.
+    ///
+    /// ```rust
+    /// use char_iter::{new, CharMapping};
+    ///
+    /// let mut rot13 = CharMapping::rotate(new('a', 'z'), 13);
+    /// rot13.insert(new('A', 'M'), new('N', 'Z'));
+    /// rot13.insert(new('N', 'Z'), new('A', 'M'));
+    /// let s: String = "Hello".chars().map(|c| rot13.translate(c)).collect();
+    /// assert_eq!(s, "Uryyb");
+    /// ```
+    pub fn rotate(range: Iter, n: i64) -> CharMapping {
+        let mut mapping = CharMapping::new();
+        let (start, end) = (range.start, range.end);
+        let shifted = match range.rotate(start, n) {
+            Some(shifted) => shifted,
+            None => return mapping,
+        };
+        if shifted == start {
+            mapping.insert(range.clone(), range);
+        } else {
+            // the characters before `split` move up to start at
+            // `shifted`, and the rest wrap around to the start.
+            let split = range.rotate(start, -n).unwrap();
+            mapping.insert(new(start, prev_char(split).unwrap()), new(shifted, end));
+            mapping.insert(new(split, end), new(start, prev_char(shifted).unwrap()));
+        }
+        mapping
+    }
+
+    /// Map the remaining characters of `from` onto those of `to`, in
+    /// order, replacing any earlier mapping for them.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `from` and `to` have different lengths.
+    pub fn insert(&mut self, from: Iter, to: Iter) {
+        assert_eq!(from.len(), to.len(), "ranges have different lengths");
+        if from.finished {
+            return
+        }
+        let (lo, hi) = (from.start, from.end);
+        let mut entries = Vec::with_capacity(self.entries.len() + 2);
+        for &(start, end, target) in &self.entries {
+            if end < lo || hi < start {
+                entries.push((start, end, target));
+                continue
+            }
+            // keep the parts of this entry on either side of `from`.
+            if start < lo {
+                entries.push((start, prev_char(lo).unwrap(), target));
+            }
+            if hi < end {
+                let after = next_char(hi).unwrap();
+                let moved = offset(target, distance(start, after) as i32).unwrap();
+                entries.push((after, end, moved));
+            }
+        }
+        entries.push((lo, hi, to.start));
+        entries.sort_by_key(|e| e.0);
+        self.entries = entries;
+    }
+
+    /// The character that `c` maps to, if any.
+    pub fn lookup(&self, c: char) -> Option<char> {
+        let i = self.entries.binary_search_by(|&(start, end, _)| {
+            if end < c {
+                Ordering::Less
+            } else if c < start {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        }).ok()?;
+        let (start, _, target) = self.entries[i];
+        offset(target, distance(start, c) as i32)
+    }
+
+    /// The character that `c` maps to, or `c` itself if it isn't
+    /// mapped.
+    pub fn translate(&self, c: char) -> char {
+        self.lookup(c).unwrap_or(c)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+    #[cfg(feature = "alloc")]
+    use CharMapping;
+
+    #[test]
+    fn map_onto() {
+        let v: Vec<(char, char)> = new('\u{D7FF}', '\u{E000}').map_onto(new('a', 'b')).collect();
+        assert_eq!(v, [('\u{D7FF}', 'a'), ('\u{E000}', 'b')]);
+        let mut pairs = new('0', '9').map_onto(new('٠', '٩'));
+        assert_eq!(pairs.len(), 10);
+        assert_eq!(pairs.next_back(), Some(('9', '٩')));
+    }
+    #[should_panic]
+    #[test]
+    fn mismatch() {
+        let _ = new('a', 'z').map_onto(new('A', 'Y'));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn mapping() {
+        let mut m = CharMapping::new();
+        m.insert(new('\u{D7F0}', '\u{E00F}'), new('\u{10000}', '\u{1001F}'));
+        assert_eq!(m.lookup('\u{D7EF}'), None);
+        assert_eq!(m.lookup('\u{D7FF}'), Some('\u{1000F}'));
+        assert_eq!(m.lookup('\u{E000}'), Some('\u{10010}'));
+        assert_eq!(m.lookup('\u{E00F}'), Some('\u{1001F}'));
+        assert_eq!(m.translate('\u{E010}'), '\u{E010}');
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn mapping_overrides() {
+        let mut m = CharMapping::new();
+        m.insert(new('a', 'z'), new('A', 'Z'));
+        m.insert(new('m', 'n'), new('0', '1'));
+        m.insert(new('x', 'x'), new('!', '!'));
+        let s: String = new('a', 'z').map(|c| m.translate(c)).collect();
+        assert_eq!(s, "ABCDEFGHIJKL01OPQRSTUVW!YZ");
+        m.insert(new('a', 'z'), new('a', 'z'));
+        assert!(new('a', 'z').all(|c| m.lookup(c) == Some(c)));
+        assert_eq!(m.entries.len(), 1);
+    }
+    #[test]
+    fn iter_rotate() {
+        let range = new('\u{D7FE}', '\u{E001}');
+        let v: Vec<Option<char>> = range.clone().map(|c| range.rotate(c, 1)).collect();
+        assert_eq!(v, [Some('\u{D7FF}'), Some('\u{E000}'), Some('\u{E001}'), Some('\u{D7FE}')]);
+        assert_eq!(range.rotate('\u{E000}', -3), Some('\u{E001}'));
+        assert_eq!(range.rotate('\u{E000}', 4), Some('\u{E000}'));
+        assert_eq!(range.rotate('\u{E002}', 0), None);
+        let full = new('\u{0}', '\u{10FFFF}');
+        assert_eq!(full.rotate('\u{0}', -1), Some('\u{10FFFF}'));
+        assert_eq!(full.rotate('\u{10FFFF}', i64::MAX), full.rotate('\u{10FFFF}', i64::MAX % full.len() as i64));
+        let mut done = new('a', 'a');
+        done.next();
+        assert_eq!(done.rotate('a', 1), None);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rotate() {
+        let m = CharMapping::rotate(new('a', 'e'), 2);
+        let s: String = new('a', 'e').map(|c| m.translate(c)).collect();
+        assert_eq!(s, "cdeab");
+        let m = CharMapping::rotate(new('a', 'e'), -1);
+        let s: String = new('a', 'e').map(|c| m.translate(c)).collect();
+        assert_eq!(s, "eabcd");
+        let m = CharMapping::rotate(new('a', 'e'), 10);
+        assert!(new('a', 'e').all(|c| m.lookup(c) == Some(c)));
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rotate_surrogates() {
+        let m = CharMapping::rotate(new('\u{D7FE}', '\u{E001}'), 1);
+        let v: Vec<char> = new('\u{D7FE}', '\u{E001}').map(|c| m.translate(c)).collect();
+        assert_eq!(v, ['\u{D7FF}', '\u{E000}', '\u{E001}', '\u{D7FE}']);
+        let m = CharMapping::rotate(new('\u{0}', '\u{10FFFF}'), -1);
+        assert_eq!(m.lookup('\u{0}'), Some('\u{10FFFF}'));
+        assert_eq!(m.lookup('\u{E000}'), Some('\u{D7FF}'));
+    }
+}
@@ -0,0 +1,204 @@
+//! Iteration with Unicode character names.
+
+use unicode_names2;
+
+use filter::{CONTROLS, NONCHARACTERS, PRIVATE_USE};
+use {new, step};
+use Dir;
+use Iter;
+use Ranges;
+
+/// An iterator adaptor yielding each character along with its name.
+///
+/// Characters with a Name property (like `'a'`, `LATIN SMALL LETTER
+/// A`) get that name. The others get a code point label as described
+/// in section 4.8 of the Unicode standard, such as `<control-0009>`,
+/// `<private-use-E000>`, `<noncharacter-FFFF>` or `<reserved-0378>`.
+///
+/// The names are those of Unicode 16.0, so characters added in 17.0
+/// get a `<reserved-...>` label.
+///
+/// This is constructed by the `names` method on `Iter` and `Ranges`.
+pub struct Names<I> {
+    iter: I,
+}
+
+/// The Tangut ideographs, whose names are algorithmic but missing from
+/// `unicode_names2`.
+static TANGUT: &[(char, char)] = &[
+    ('\u{17000}', '\u{187F7}'),
+    ('\u{18D00}', '\u{18D08}'),
+];
+
+/// Whether `c` lies in one of the ranges of `table`.
+fn in_table(c: char, table: &[(char, char)]) -> bool {
+    table.iter().any(|&(lo, hi)| lo <= c && c <= hi)
+}
+
+/// The name of `c`, or its code point label if it has no name.
+fn name(c: char) -> String {
+    match unicode_names2::name(c) {
+        Some(name) => name.to_string(),
+        None if in_table(c, TANGUT) => format!("TANGUT IDEOGRAPH-{:04X}", c as u32),
+        None => {
+            let kind = if in_table(c, CONTROLS) {
+                "control"
+            } else if in_table(c, NONCHARACTERS) {
+                "noncharacter"
+            } else if in_table(c, PRIVATE_USE) {
+                "private-use"
+            } else {
+                "reserved"
+            };
+            format!("<{}-{:04X}>", kind, c as u32)
+        }
+    }
+}
+
+impl<I: Iterator<Item = char>> Iterator for Names<I> {
+    type Item = (char, String);
+
+    fn next(&mut self) -> Option<(char, String)> {
+        self.iter.next().map(|c| (c, name(c)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for Names<I> {
+    fn next_back(&mut self) -> Option<(char, String)> {
+        self.iter.next_back().map(|c| (c, name(c)))
+    }
+}
+
+impl<I: ExactSizeIterator<Item = char>> ExactSizeIterator for Names<I> {}
+
+impl Iter {
+    /// Pair each character with its Unicode name (or code point
+    /// label).
+    ///
+    /// ```rust
+    /// let mut names = char_iter::new('`', 'a').names();
+    /// assert_eq!(names.next(), Some(('`', "GRAVE ACCENT".to_string())));
+    /// assert_eq!(names.next(), Some(('a', "LATIN SMALL LETTER A".to_string())));
+    /// ```
+    pub fn names(self) -> Names<Iter> {
+        Names { iter: self }
+    }
+
+    /// Restrict this iterator to the characters whose name (or code
+    /// point label, as for `names`) satisfies `pred`.
+    ///
+    /// This looks up the name of every character in the range.
+    ///
+    /// ```rust
+    /// let v: String = char_iter::new('\u{2500}', '\u{257F}')
+    ///     .filter_names(|n| n.ends_with("DOUBLE DOWN AND RIGHT"))
+    ///     .collect();
+    /// assert_eq!(v, "\u{2554}");
+    /// ```
+    pub fn filter_names<F: FnMut(&str) -> bool>(self, pred: F) -> Ranges {
+        Ranges::from(self).filter_names(pred)
+    }
+
+    /// Restrict this iterator to the characters whose name contains
+    /// `needle`, such as `"ARROW"` or `"WITH ACUTE"`.
+    ///
+    /// ```rust
+    /// let v: String = char_iter::new('\u{0}', '\u{FF}').with_name_containing("CAPITAL LETTER A WITH").collect();
+    /// assert_eq!(v, "ÀÁÂÃÄÅ");
+    /// ```
+    pub fn with_name_containing(self, needle: &str) -> Ranges {
+        Ranges::from(self).with_name_containing(needle)
+    }
+}
+
+impl Ranges {
+    /// Pair each character with its Unicode name (or code point
+    /// label), as `Iter::names`.
+    pub fn names(self) -> Names<Ranges> {
+        Names { iter: self }
+    }
+
+    /// Restrict this iterator further, to the characters whose name
+    /// satisfies `pred`, as `Iter::filter_names`.
+    pub fn filter_names<F: FnMut(&str) -> bool>(self, mut pred: F) -> Ranges {
+        // the runs of consecutive matching characters
+        let mut runs: Vec<(char, char)> = Vec::new();
+        for c in self {
+            if !pred(&name(c)) {
+                continue;
+            }
+            match runs.last_mut() {
+                Some(run) if step(run.1, Dir::Forward) == c => run.1 = c,
+                _ => runs.push((c, c)),
+            }
+        }
+        Ranges::from(new('\u{0}', '\u{10FFFF}')).restrict(&runs)
+    }
+
+    /// Restrict this iterator further, to the characters whose name
+    /// contains `needle`, as `Iter::with_name_containing`.
+    pub fn with_name_containing(self, needle: &str) -> Ranges {
+        self.filter_names(|n| n.contains(needle))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn labels() {
+        let v: Vec<(char, String)> = new('\u{8}', '\u{9}').names().collect();
+        assert_eq!(v, &[('\u{8}', "<control-0008>".to_string()),
+                        ('\u{9}', "<control-0009>".to_string())]);
+        let v: Vec<String> = new('\u{377}', '\u{378}').names().map(|(_, n)| n).collect();
+        assert_eq!(v, &["GREEK SMALL LETTER PAMPHYLIAN DIGAMMA", "<reserved-0378>"]);
+        let v: Vec<String> = new('\u{FFFF}', '\u{10000}').names().rev().map(|(_, n)| n).collect();
+        assert_eq!(v, &["LINEAR B SYLLABLE B008 A", "<noncharacter-FFFF>"]);
+        let (_, n) = new('\u{10FFFD}', '\u{10FFFD}').names().next().unwrap();
+        assert_eq!(n, "<private-use-10FFFD>");
+    }
+    #[test]
+    fn algorithmic() {
+        let v: Vec<String> = new('\u{4E00}', '\u{4E00}').names()
+            .chain(new('\u{AC00}', '\u{AC00}').names())
+            .map(|(_, n)| n)
+            .collect();
+        assert_eq!(v, &["CJK UNIFIED IDEOGRAPH-4E00", "HANGUL SYLLABLE GA"]);
+        let v: Vec<String> = new('\u{187F7}', '\u{187F8}').names()
+            .chain(new('\u{18D08}', '\u{18D08}').names())
+            .map(|(_, n)| n)
+            .collect();
+        assert_eq!(v, &["TANGUT IDEOGRAPH-187F7", "<reserved-187F8>", "TANGUT IDEOGRAPH-18D08"]);
+    }
+    #[cfg(feature = "unicode-data")]
+    #[test]
+    fn assigned_not_reserved() {
+        use UnicodeVersion;
+        // the names only go up to Unicode 16.0
+        let reserved: Vec<char> = new('\u{0}', '\u{10FFFF}').assigned_in(UnicodeVersion::V16_0).names()
+            .filter(|(_, n)| n.starts_with("<reserved-"))
+            .map(|(c, _)| c)
+            .collect();
+        assert_eq!(reserved, &[] as &[char]);
+    }
+    #[test]
+    fn name_queries() {
+        let arrows = new('\u{2190}', '\u{21FF}').with_name_containing("ARROW");
+        assert_eq!(arrows.len(), 102);
+        // runs across the surrogates are a single range
+        let v = new('\u{D7FE}', '\u{E001}').filter_names(|n| n.starts_with("<"));
+        assert_eq!(v.ranges.len(), 1);
+        assert_eq!(v.collect::<Vec<_>>(), &['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']);
+        assert_eq!(new('a', 'z').with_name_containing("DIGIT").len(), 0);
+    }
+    #[test]
+    fn ranges() {
+        let mut names = new('\u{0}', '\u{7F}').printable_only().names();
+        assert_eq!(names.len(), 95);
+        assert_eq!(names.next_back(), Some(('~', "TILDE".to_string())));
+    }
+}
@@ -0,0 +1,111 @@
+//! Parallel iteration with rayon.
+
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+
+use {forward, Iter};
+
+/// A parallel iterator over a linear range of characters.
+///
+/// This is constructed by `into_par_iter` on `Iter`. The range is
+/// split exactly by position, so each half has the right number of
+/// characters even when the surrogate range lies between them.
+pub struct ParIter {
+    iter: Iter,
+}
+
+impl IntoParallelIterator for Iter {
+    type Iter = ParIter;
+    type Item = char;
+
+    fn into_par_iter(self) -> ParIter {
+        ParIter { iter: self }
+    }
+}
+
+impl ParallelIterator for ParIter {
+    type Item = char;
+
+    fn drive_unindexed<C: UnindexedConsumer<char>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl IndexedParallelIterator for ParIter {
+    fn drive<C: Consumer<char>>(self, consumer: C) -> C::Result {
+        bridge(self, consumer)
+    }
+
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+
+    fn with_producer<CB: ProducerCallback<char>>(self, callback: CB) -> CB::Output {
+        callback.callback(CharProducer { iter: self.iter })
+    }
+}
+
+struct CharProducer {
+    iter: Iter,
+}
+
+impl Producer for CharProducer {
+    type Item = char;
+    type IntoIter = Iter;
+
+    fn into_iter(self) -> Iter {
+        self.iter
+    }
+
+    fn split_at(self, index: usize) -> (CharProducer, CharProducer) {
+        let Iter { start, end, finished } = self.iter;
+        let len = self.iter.len();
+        let empty = Iter { start, end, finished: true };
+        let (left, right) = if index == 0 {
+            (empty, self.iter)
+        } else if index >= len {
+            (self.iter, empty)
+        } else {
+            debug_assert!(!finished);
+            (Iter { start, end: forward(start, index as u32 - 1), finished },
+             Iter { start: forward(start, index as u32), end, finished })
+        };
+        (CharProducer { iter: left }, CharProducer { iter: right })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rayon::prelude::*;
+
+    use new;
+
+    #[test]
+    fn collect() {
+        let v: Vec<char> = new('\u{0}', '\u{10FFFF}').into_par_iter().collect();
+        assert_eq!(v, new('\u{0}', '\u{10FFFF}').collect::<Vec<_>>());
+    }
+    #[test]
+    fn indexed() {
+        let iter = new('\u{D7F0}', '\u{E00F}').into_par_iter();
+        assert_eq!(iter.len(), 32);
+        let v: Vec<(usize, char)> = iter.enumerate().rev().collect();
+        assert_eq!(v[0], (31, '\u{E00F}'));
+        assert_eq!(v[15], (16, '\u{E000}'));
+        assert_eq!(v[16], (15, '\u{D7FF}'));
+        let count = new('a', 'z').into_par_iter().with_min_len(1).with_max_len(1)
+            .filter(|c| "aeiou".contains(*c))
+            .count();
+        assert_eq!(count, 5);
+    }
+    #[test]
+    fn empty() {
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert_eq!(iter.into_par_iter().count(), 0);
+    }
+}
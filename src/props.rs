@@ -0,0 +1,352 @@
+//! Filtering by Unicode properties from the ICU4X data.
+
+use std::ops::RangeInclusive;
+
+use icu_properties::props;
+use icu_properties::CodePointMapData;
+
+use Iter;
+use Ranges;
+
+/// The East_Asian_Width property of a character (UAX #11).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum EastAsianWidth {
+    /// Neutral (`N`): not East Asian.
+    Neutral,
+    /// Ambiguous (`A`): wide or narrow depending on context.
+    Ambiguous,
+    /// Halfwidth (`H`), like `U+FF61 HALFWIDTH IDEOGRAPHIC FULL STOP`.
+    Halfwidth,
+    /// Fullwidth (`F`), like `U+FF01 FULLWIDTH EXCLAMATION MARK`.
+    Fullwidth,
+    /// Narrow (`Na`), like ASCII.
+    Narrow,
+    /// Wide (`W`), like CJK ideographs.
+    Wide,
+}
+
+impl EastAsianWidth {
+    fn to_icu(self) -> props::EastAsianWidth {
+        match self {
+            EastAsianWidth::Neutral => props::EastAsianWidth::Neutral,
+            EastAsianWidth::Ambiguous => props::EastAsianWidth::Ambiguous,
+            EastAsianWidth::Halfwidth => props::EastAsianWidth::Halfwidth,
+            EastAsianWidth::Fullwidth => props::EastAsianWidth::Fullwidth,
+            EastAsianWidth::Narrow => props::EastAsianWidth::Narrow,
+            EastAsianWidth::Wide => props::EastAsianWidth::Wide,
+        }
+    }
+
+    fn ranges(self) -> Vec<(char, char)> {
+        let map = CodePointMapData::<props::EastAsianWidth>::new();
+        char_ranges(map.iter_ranges_for_value(self.to_icu()))
+    }
+}
+
+/// The Bidi_Class property of a character (UAX #9).
+#[allow(non_camel_case_types)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BidiClass {
+    /// Left_To_Right
+    L,
+    /// Right_To_Left
+    R,
+    /// Arabic_Letter
+    AL,
+    /// European_Number
+    EN,
+    /// European_Separator
+    ES,
+    /// European_Terminator
+    ET,
+    /// Arabic_Number
+    AN,
+    /// Common_Separator
+    CS,
+    /// Nonspacing_Mark
+    NSM,
+    /// Boundary_Neutral
+    BN,
+    /// Paragraph_Separator
+    B,
+    /// Segment_Separator
+    S,
+    /// White_Space
+    WS,
+    /// Other_Neutral
+    ON,
+    /// Left_To_Right_Embedding
+    LRE,
+    /// Left_To_Right_Override
+    LRO,
+    /// Right_To_Left_Embedding
+    RLE,
+    /// Right_To_Left_Override
+    RLO,
+    /// Pop_Directional_Format
+    PDF,
+    /// Left_To_Right_Isolate
+    LRI,
+    /// Right_To_Left_Isolate
+    RLI,
+    /// First_Strong_Isolate
+    FSI,
+    /// Pop_Directional_Isolate
+    PDI,
+}
+
+impl BidiClass {
+    fn to_icu(self) -> props::BidiClass {
+        match self {
+            BidiClass::L => props::BidiClass::LeftToRight,
+            BidiClass::R => props::BidiClass::RightToLeft,
+            BidiClass::AL => props::BidiClass::ArabicLetter,
+            BidiClass::EN => props::BidiClass::EuropeanNumber,
+            BidiClass::ES => props::BidiClass::EuropeanSeparator,
+            BidiClass::ET => props::BidiClass::EuropeanTerminator,
+            BidiClass::AN => props::BidiClass::ArabicNumber,
+            BidiClass::CS => props::BidiClass::CommonSeparator,
+            BidiClass::NSM => props::BidiClass::NonspacingMark,
+            BidiClass::BN => props::BidiClass::BoundaryNeutral,
+            BidiClass::B => props::BidiClass::ParagraphSeparator,
+            BidiClass::S => props::BidiClass::SegmentSeparator,
+            BidiClass::WS => props::BidiClass::WhiteSpace,
+            BidiClass::ON => props::BidiClass::OtherNeutral,
+            BidiClass::LRE => props::BidiClass::LeftToRightEmbedding,
+            BidiClass::LRO => props::BidiClass::LeftToRightOverride,
+            BidiClass::RLE => props::BidiClass::RightToLeftEmbedding,
+            BidiClass::RLO => props::BidiClass::RightToLeftOverride,
+            BidiClass::PDF => props::BidiClass::PopDirectionalFormat,
+            BidiClass::LRI => props::BidiClass::LeftToRightIsolate,
+            BidiClass::RLI => props::BidiClass::RightToLeftIsolate,
+            BidiClass::FSI => props::BidiClass::FirstStrongIsolate,
+            BidiClass::PDI => props::BidiClass::PopDirectionalIsolate,
+        }
+    }
+
+    fn ranges(self) -> Vec<(char, char)> {
+        let map = CodePointMapData::<props::BidiClass>::new();
+        char_ranges(map.iter_ranges_for_value(self.to_icu()))
+    }
+}
+
+/// The characters with canonical combining class `class`.
+fn combining_class_ranges(class: u8) -> Vec<(char, char)> {
+    let map = CodePointMapData::<props::CanonicalCombiningClass>::new();
+    char_ranges(map.iter_ranges_for_value(props::CanonicalCombiningClass(class)))
+}
+
+/// Convert ranges of code points into ranges of characters, by
+/// cutting out any surrogates.
+pub(crate) fn char_ranges<I>(ranges: I) -> Vec<(char, char)>
+    where I: Iterator<Item = RangeInclusive<u32>>
+{
+    let mut out = Vec::new();
+    for r in ranges {
+        let (lo, hi) = (*r.start(), *r.end());
+        if lo < ::SUR_START {
+            out.push((lo, hi.min(::BEFORE_SUR)));
+        }
+        if hi > ::SUR_END {
+            out.push((lo.max(::AFTER_SUR), hi));
+        }
+    }
+    out.into_iter()
+        .map(|(lo, hi)| (::std::char::from_u32(lo).unwrap(), ::std::char::from_u32(hi).unwrap()))
+        .collect()
+}
+
+/// Merge two sorted lists of ranges, none of which overlap, into one.
+fn union(mut ranges: Vec<(char, char)>, other: Vec<(char, char)>) -> Vec<(char, char)> {
+    ranges.extend(other);
+    ranges.sort();
+    ranges
+}
+
+impl Iter {
+    /// Restrict this iterator to the characters with the East Asian
+    /// Width `width`.
+    ///
+    /// ```rust
+    /// use char_iter::EastAsianWidth;
+    ///
+    /// let v: Vec<char> = char_iter::new('\u{FF5E}', '\u{FF62}')
+    ///     .filter_east_asian_width(EastAsianWidth::Halfwidth)
+    ///     .collect();
+    /// assert_eq!(v, &['\u{FF61}', '\u{FF62}']);
+    /// ```
+    pub fn filter_east_asian_width(self, width: EastAsianWidth) -> Ranges {
+        Ranges::from(self).filter_east_asian_width(width)
+    }
+
+    /// Restrict this iterator to the characters that are Wide or
+    /// Fullwidth, that is, those that take up two cells in a
+    /// terminal.
+    ///
+    /// ```rust
+    /// let v: Vec<char> = char_iter::new('\u{FF00}', '\u{FF02}').east_asian_wide_only().collect();
+    /// assert_eq!(v, &['\u{FF01}', '\u{FF02}']);
+    /// ```
+    pub fn east_asian_wide_only(self) -> Ranges {
+        Ranges::from(self).east_asian_wide_only()
+    }
+
+    /// Restrict this iterator to the characters with the
+    /// bidirectional class `class`.
+    ///
+    /// ```rust
+    /// use char_iter::BidiClass;
+    ///
+    /// // ARABIC-INDIC DIGIT ZERO to NINE are Arabic numbers, but
+    /// // EXTENDED ARABIC-INDIC DIGIT ZERO is a European number
+    /// let arabic = char_iter::new('\u{660}', '\u{669}').filter_bidi_class(BidiClass::AN);
+    /// assert_eq!(arabic.len(), 10);
+    /// let european = char_iter::new('\u{6F0}', '\u{6F0}').filter_bidi_class(BidiClass::EN);
+    /// assert_eq!(european.len(), 1);
+    /// ```
+    pub fn filter_bidi_class(self, class: BidiClass) -> Ranges {
+        Ranges::from(self).filter_bidi_class(class)
+    }
+
+    /// Restrict this iterator to the strong right-to-left characters,
+    /// those with bidirectional class `R` or `AL`.
+    ///
+    /// ```rust
+    /// // HEBREW PUNCTUATION NUN HAFUKHA, then HEBREW POINT QAMATS QATAN
+    /// let rtl: Vec<char> = char_iter::new('\u{5C6}', '\u{5C7}').right_to_left_only().collect();
+    /// assert_eq!(rtl, &['\u{5C6}']);
+    /// ```
+    pub fn right_to_left_only(self) -> Ranges {
+        Ranges::from(self).right_to_left_only()
+    }
+
+    /// Restrict this iterator to the characters with canonical
+    /// combining class `class` (for example, 230 for marks above).
+    ///
+    /// ```rust
+    /// let above = char_iter::new('\u{300}', '\u{304}').filter_combining_class(230);
+    /// assert_eq!(above.len(), 5);
+    /// ```
+    pub fn filter_combining_class(self, class: u8) -> Ranges {
+        Ranges::from(self).filter_combining_class(class)
+    }
+
+    /// Restrict this iterator to the characters with a non-zero
+    /// canonical combining class, that is, those that are reordered
+    /// by normalization.
+    ///
+    /// ```rust
+    /// let marks: Vec<char> = char_iter::new('a', '\u{302}').combining_only().take(2).collect();
+    /// assert_eq!(marks, &['\u{300}', '\u{301}']);
+    /// ```
+    pub fn combining_only(self) -> Ranges {
+        Ranges::from(self).combining_only()
+    }
+}
+
+impl Ranges {
+    /// Restrict this iterator further, to the characters with the
+    /// East Asian Width `width`.
+    pub fn filter_east_asian_width(self, width: EastAsianWidth) -> Ranges {
+        self.restrict(&width.ranges())
+    }
+
+    /// Restrict this iterator further, to the characters that are
+    /// Wide or Fullwidth.
+    pub fn east_asian_wide_only(self) -> Ranges {
+        self.restrict(&union(EastAsianWidth::Wide.ranges(), EastAsianWidth::Fullwidth.ranges()))
+    }
+
+    /// Restrict this iterator further, to the characters with the
+    /// bidirectional class `class`.
+    pub fn filter_bidi_class(self, class: BidiClass) -> Ranges {
+        self.restrict(&class.ranges())
+    }
+
+    /// Restrict this iterator further, to the strong right-to-left
+    /// characters.
+    pub fn right_to_left_only(self) -> Ranges {
+        self.restrict(&union(BidiClass::R.ranges(), BidiClass::AL.ranges()))
+    }
+
+    /// Restrict this iterator further, to the characters with
+    /// canonical combining class `class`.
+    pub fn filter_combining_class(self, class: u8) -> Ranges {
+        self.restrict(&combining_class_ranges(class))
+    }
+
+    /// Restrict this iterator further, to the characters with a
+    /// non-zero canonical combining class.
+    pub fn combining_only(self) -> Ranges {
+        self.exclude(&combining_class_ranges(0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use new;
+
+    #[test]
+    fn surrogates_cut_out() {
+        let v = char_ranges(vec![0xD700..=0xE000, 0xD800..=0xDFFF, 0xDFFF..=0xE001].into_iter());
+        assert_eq!(v, &[('\u{D700}', '\u{D7FF}'), ('\u{E000}', '\u{E000}'), ('\u{E000}', '\u{E001}')]);
+    }
+    #[test]
+    fn ascii() {
+        let iter = new('\u{0}', '\u{7F}').filter_east_asian_width(EastAsianWidth::Narrow);
+        assert_eq!(iter.len(), 95);
+        assert_eq!(new('a', 'z').east_asian_wide_only().len(), 0);
+    }
+    #[test]
+    fn partition() {
+        use self::EastAsianWidth::*;
+        let total: usize = [Neutral, Ambiguous, Halfwidth, Fullwidth, Narrow, Wide].iter()
+            .map(|&w| new('\u{0}', '\u{10FFFF}').filter_east_asian_width(w).len())
+            .sum();
+        assert_eq!(total, new('\u{0}', '\u{10FFFF}').len());
+    }
+    #[test]
+    fn wide() {
+        let mut iter = new('\u{0}', '\u{10FFFF}').east_asian_wide_only();
+        assert_eq!(iter.next(), Some('\u{1100}'));
+        assert!(iter.any(|c| c == '\u{4E00}'));
+        assert!(iter.any(|c| c == '\u{FF01}'));
+    }
+
+    #[test]
+    fn bidi() {
+        let iter = new('\u{0}', '\u{7F}').filter_bidi_class(BidiClass::L);
+        assert_eq!(iter.len(), 52);
+        let v: Vec<char> = new('\u{5D0}', '\u{5D2}').filter_bidi_class(BidiClass::R).rev().collect();
+        assert_eq!(v, &['\u{5D2}', '\u{5D1}', '\u{5D0}']);
+        let v: Vec<char> = new('\u{2066}', '\u{2069}').filter_bidi_class(BidiClass::FSI).collect();
+        assert_eq!(v, &['\u{2068}']);
+    }
+    #[test]
+    fn right_to_left() {
+        let iter = new('\u{0}', '\u{10FFFF}').right_to_left_only();
+        let r = new('\u{0}', '\u{10FFFF}').filter_bidi_class(BidiClass::R).len();
+        let al = new('\u{0}', '\u{10FFFF}').filter_bidi_class(BidiClass::AL).len();
+        assert_eq!(iter.len(), r + al);
+        assert_eq!(new('a', 'z').right_to_left_only().len(), 0);
+    }
+
+    #[test]
+    fn combining_class() {
+        // COMBINING CEDILLA is attached below
+        let v: Vec<char> = new('\u{300}', '\u{36F}').filter_combining_class(202).collect();
+        assert!(v.contains(&'\u{327}'));
+        assert_eq!(new('\u{0}', '\u{7F}').filter_combining_class(230).len(), 0);
+        assert_eq!(new('\u{0}', '\u{7F}').filter_combining_class(0).len(), 128);
+    }
+    #[test]
+    fn combining() {
+        let iter = new('\u{0}', '\u{10FFFF}');
+        let len = iter.len();
+        let zero = new('\u{0}', '\u{10FFFF}').filter_combining_class(0).len();
+        assert_eq!(iter.combining_only().len(), len - zero);
+        let v: Vec<char> = new('\u{0}', '\u{10FFFF}').combining_only().rev().take(1).collect();
+        assert_eq!(v, &['\u{1E94A}']); // ADLAM NUKTA
+    }
+}
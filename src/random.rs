@@ -0,0 +1,267 @@
+//! Random sampling of characters.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use rand::distr::Distribution;
+use rand::seq::index;
+use rand::Rng;
+
+use forward;
+use Iter;
+use Ranges;
+
+impl Iter {
+    /// Choose `n` distinct characters of this range uniformly at
+    /// random, in a random order.
+    ///
+    /// This picks indices into the range without materializing it,
+    /// so it takes time and memory proportional to `n` only.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `n` is larger than the number of characters
+    /// remaining.
+    ///
+    /// ```rust
+    /// extern crate char_iter;
+    /// extern crate rand;
+    ///
+    /// # fn main() {
+    /// let mut rng = rand::rng();
+    /// let cjk = char_iter::new('\u{4E00}', '\u{9FFF}').sample_distinct(&mut rng, 100);
+    /// assert_eq!(cjk.len(), 100);
+    /// # }
+    /// ```
+    pub fn sample_distinct<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<char> {
+        let len = self.len();
+        assert!(n <= len, "cannot sample {} distinct characters from {}", n, len);
+        index::sample(rng, len, n).into_iter()
+            .map(|i| forward(self.start, i as u32))
+            .collect()
+    }
+
+    /// A string of `len` characters, each chosen independently and
+    /// uniformly from this range.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `len` is positive and the range is empty.
+    ///
+    /// ```rust
+    /// extern crate char_iter;
+    /// extern crate rand;
+    ///
+    /// # fn main() {
+    /// let mut rng = rand::rng();
+    /// let pin = char_iter::new('0', '9').sample_string(&mut rng, 6);
+    /// assert!(pin.len() == 6 && pin.chars().all(|c| c.is_ascii_digit()));
+    /// # }
+    /// ```
+    pub fn sample_string<R: Rng + ?Sized>(&self, rng: &mut R, len: usize) -> String {
+        self.sample_iter(rng).take(len).collect()
+    }
+
+    /// A string of random characters from this range, as
+    /// `sample_string`, with its length (in characters) drawn from
+    /// `lengths`.
+    ///
+    /// ```rust
+    /// extern crate char_iter;
+    /// extern crate rand;
+    ///
+    /// use rand::distr::Uniform;
+    ///
+    /// # fn main() {
+    /// let mut rng = rand::rng();
+    /// let word = char_iter::new('a', 'z').sample_string_with(&mut rng, &Uniform::new(3, 8).unwrap());
+    /// assert!(3 <= word.len() && word.len() < 8);
+    /// # }
+    /// ```
+    pub fn sample_string_with<R, D>(&self, rng: &mut R, lengths: &D) -> String
+        where R: Rng + ?Sized, D: Distribution<usize>
+    {
+        let len = lengths.sample(rng);
+        self.sample_string(rng, len)
+    }
+}
+
+impl Ranges {
+    /// Choose `n` distinct characters of these ranges uniformly at
+    /// random, in a random order, as `Iter::sample_distinct`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `n` is larger than the number of characters
+    /// remaining.
+    pub fn sample_distinct<R: Rng + ?Sized>(&self, rng: &mut R, n: usize) -> Vec<char> {
+        let len = self.len();
+        assert!(n <= len, "cannot sample {} distinct characters from {}", n, len);
+        index::sample(rng, len, n).into_iter()
+            .map(|i| self.index(i))
+            .collect()
+    }
+
+    /// A string of `len` characters, each chosen independently and
+    /// uniformly from these ranges, as `Iter::sample_string`.
+    ///
+    /// # Panics
+    ///
+    /// This panics if `len` is positive and there are no characters
+    /// remaining.
+    pub fn sample_string<R: Rng + ?Sized>(&self, rng: &mut R, len: usize) -> String {
+        self.sample_iter(rng).take(len).collect()
+    }
+
+    /// A string of random characters from these ranges, with its
+    /// length drawn from `lengths`, as `Iter::sample_string_with`.
+    pub fn sample_string_with<R, D>(&self, rng: &mut R, lengths: &D) -> String
+        where R: Rng + ?Sized, D: Distribution<usize>
+    {
+        let len = lengths.sample(rng);
+        self.sample_string(rng, len)
+    }
+
+    /// The `i`th remaining character, which must exist.
+    fn index(&self, mut i: usize) -> char {
+        for r in &self.ranges {
+            if i < r.len() {
+                return forward(r.start, i as u32)
+            }
+            i -= r.len();
+        }
+        unreachable!()
+    }
+}
+
+/// Uniform sampling of single characters from the range, skipping
+/// the surrogates by index arithmetic rather than rejection.
+///
+/// # Panics
+///
+/// Sampling panics if the range is empty.
+///
+/// ```rust
+/// extern crate char_iter;
+/// extern crate rand;
+///
+/// use rand::Rng;
+///
+/// # fn main() {
+/// let c: char = rand::rng().sample(char_iter::new('\u{D7FF}', '\u{E000}'));
+/// assert!(c == '\u{D7FF}' || c == '\u{E000}');
+/// # }
+/// ```
+impl Distribution<char> for Iter {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        let len = self.len();
+        assert!(len > 0, "cannot sample from an empty range");
+        forward(self.start, rng.random_range(0..len) as u32)
+    }
+}
+
+/// Uniform sampling of single characters from the ranges, as for
+/// `Iter`.
+///
+/// # Panics
+///
+/// Sampling panics if there are no characters remaining.
+impl Distribution<char> for Ranges {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> char {
+        let len = self.len();
+        assert!(len > 0, "cannot sample from an empty range");
+        self.index(rng.random_range(0..len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::distr::Uniform;
+    use rand::rngs::SmallRng;
+    use rand::SeedableRng;
+    use super::*;
+    use new;
+
+    #[test]
+    fn forward_over_surrogates() {
+        assert_eq!(forward('a', 0), 'a');
+        assert_eq!(forward('\u{D7FF}', 1), '\u{E000}');
+        assert_eq!(forward('\u{D7FE}', 3), '\u{E001}');
+        assert_eq!(forward('\u{E000}', 1), '\u{E001}');
+    }
+    #[test]
+    fn distinct() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut v = new('\u{D7FC}', '\u{E003}').sample_distinct(&mut rng, 8);
+        v.sort();
+        assert_eq!(v, new('\u{D7FC}', '\u{E003}').collect::<Vec<_>>());
+        for _ in 0..100 {
+            let mut v = new('a', 'z').sample_distinct(&mut rng, 5);
+            assert!(v.iter().all(|c| c.is_ascii_lowercase()));
+            v.sort();
+            v.dedup();
+            assert_eq!(v.len(), 5);
+        }
+    }
+    #[test]
+    fn distinct_ranges() {
+        let mut rng = SmallRng::seed_from_u64(1);
+        let iter = new('\u{0}', '\u{7F}').printable_only().skip_private_use();
+        let mut v = iter.sample_distinct(&mut rng, 95);
+        v.sort();
+        assert_eq!(v, iter.collect::<Vec<_>>());
+        let iter = Ranges::from(new('a', 'z')).restrict(&[('a', 'b'), ('y', 'z')]);
+        for _ in 0..100 {
+            let v = iter.sample_distinct(&mut rng, 1);
+            assert!("abyz".contains(v[0]));
+        }
+    }
+    #[test]
+    fn distribution() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let mut counts = [0; 4];
+        for c in new('\u{D7FE}', '\u{E001}').sample_iter(&mut rng).take(4000) {
+            counts[match c {
+                '\u{D7FE}' => 0,
+                '\u{D7FF}' => 1,
+                '\u{E000}' => 2,
+                '\u{E001}' => 3,
+                _ => panic!("{:?} out of range", c),
+            }] += 1;
+        }
+        assert!(counts.iter().all(|&n| n > 900 && n < 1100), "{:?}", counts);
+        let iter = Ranges::from(new('a', 'z')).restrict(&[('a', 'a'), ('z', 'z')]);
+        let s: String = (&iter).sample_iter(&mut rng).take(100).collect();
+        assert!(s.contains('a') && s.contains('z') && s.len() == 100);
+    }
+    #[test]
+    fn strings() {
+        let mut rng = SmallRng::seed_from_u64(3);
+        let s = new('\u{D7FF}', '\u{E000}').sample_string(&mut rng, 10);
+        assert_eq!(s.chars().count(), 10);
+        assert!(s.chars().all(|c| c == '\u{D7FF}' || c == '\u{E000}'));
+        let iter = Ranges::from(new('a', 'z')).restrict(&[('a', 'b'), ('y', 'z')]);
+        for len in 0..20 {
+            let s = iter.sample_string_with(&mut rng, &Uniform::new_inclusive(len, len).unwrap());
+            assert_eq!(s.len(), len);
+            assert!(s.chars().all(|c| "abyz".contains(c)));
+        }
+        let mut empty = new('a', 'a');
+        empty.next();
+        assert_eq!(empty.sample_string(&mut rng, 0), "");
+    }
+    #[test]
+    #[should_panic]
+    fn sample_empty() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        let mut iter = new('a', 'a');
+        iter.next();
+        iter.sample(&mut rng);
+    }
+    #[test]
+    #[should_panic]
+    fn too_many() {
+        let mut rng = SmallRng::seed_from_u64(0);
+        new('a', 'c').sample_distinct(&mut rng, 4);
+    }
+}
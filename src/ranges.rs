@@ -0,0 +1,315 @@
+//! Iteration over several disjoint ranges of characters.
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+use core::cmp;
+use core::ops::RangeInclusive;
+
+use {new, step};
+use Dir;
+use Iter;
+
+/// An iterator over the characters of several disjoint ranges, in
+/// increasing order.
+///
+/// This is constructed by the filtering adaptors on `Iter` (such as
+/// `skip_noncharacters`), and, unlike `Iterator::filter`, it knows
+/// exactly how many characters remain.
+pub struct Ranges {
+    pub(crate) ranges: VecDeque<Iter>,
+    len: usize,
+}
+
+impl From<Iter> for Ranges {
+    fn from(iter: Iter) -> Ranges {
+        let len = iter.len();
+        let mut ranges = VecDeque::new();
+        if !iter.finished {
+            ranges.push_back(iter);
+        }
+        Ranges {
+            ranges,
+            len,
+        }
+    }
+}
+
+/// The characters of several ranges, which can be unsorted,
+/// overlapping or empty.
+///
+/// ```rust
+/// use char_iter::Ranges;
+///
+/// let v: String = Ranges::from(&['x'..='z', 'a'..='b', 'y'..='y'][..]).collect();
+/// assert_eq!(v, "abxyz");
+/// ```
+impl<'a> From<&'a [RangeInclusive<char>]> for Ranges {
+    fn from(ranges: &'a [RangeInclusive<char>]) -> Ranges {
+        let mut sorted: Vec<(char, char)> = ranges.iter()
+            .filter(|r| !r.is_empty())
+            .map(|r| (*r.start(), *r.end()))
+            .collect();
+        sorted.sort();
+        Ranges::from(new('\u{0}', '\u{10FFFF}')).restrict(&merge(sorted))
+    }
+}
+
+/// Merge sorted inclusive ranges that overlap or touch, counting the
+/// characters either side of the surrogates as touching.
+fn merge<I: IntoIterator<Item = (char, char)>>(sorted: I) -> Vec<(char, char)> {
+    let mut merged: Vec<(char, char)> = Vec::new();
+    for (lo, hi) in sorted {
+        match merged.last_mut() {
+            Some(last) if last.1 == '\u{10FFFF}' || step(last.1, Dir::Forward) >= lo => {
+                last.1 = cmp::max(last.1, hi);
+            }
+            _ => merged.push((lo, hi)),
+        }
+    }
+    merged
+}
+
+impl Ranges {
+    /// The remaining characters as maximal inclusive ranges, in
+    /// increasing order.
+    ///
+    /// ```rust
+    /// use char_iter::Ranges;
+    ///
+    /// let ranges = Ranges::from(&['a'..='c', 'd'..='e', 'x'..='z'][..]);
+    /// assert_eq!(ranges.to_range_inclusive_vec(), ['a'..='e', 'x'..='z']);
+    /// ```
+    pub fn to_range_inclusive_vec(&self) -> Vec<RangeInclusive<char>> {
+        self.runs().into_iter().map(|(lo, hi)| lo..=hi).collect()
+    }
+
+    /// The smallest remaining character that is at least `c`.
+    ///
+    /// This is a binary search, taking time logarithmic in the number
+    /// of ranges.
+    ///
+    /// ```rust
+    /// use char_iter::Ranges;
+    ///
+    /// let ranges = Ranges::from(&['a'..='c', 'x'..='z'][..]);
+    /// assert_eq!(ranges.next_member_at_or_after('b'), Some('b'));
+    /// assert_eq!(ranges.next_member_at_or_after('d'), Some('x'));
+    /// assert_eq!(ranges.next_member_at_or_after('~'), None);
+    /// ```
+    pub fn next_member_at_or_after(&self, c: char) -> Option<char> {
+        let i = self.ranges.partition_point(|r| r.end < c);
+        self.ranges.get(i).map(|r| cmp::max(r.start, c))
+    }
+
+    /// The largest remaining character that is at most `c`, as
+    /// `next_member_at_or_after`.
+    ///
+    /// ```rust
+    /// use char_iter::Ranges;
+    ///
+    /// let ranges = Ranges::from(&['a'..='c', 'x'..='z'][..]);
+    /// assert_eq!(ranges.prev_member_at_or_before('w'), Some('c'));
+    /// assert_eq!(ranges.prev_member_at_or_before('A'), None);
+    /// ```
+    pub fn prev_member_at_or_before(&self, c: char) -> Option<char> {
+        let i = self.ranges.partition_point(|r| r.start <= c);
+        i.checked_sub(1).map(|i| cmp::min(self.ranges[i].end, c))
+    }
+
+    /// The characters of `range` that are not remaining in this
+    /// iterator, which is the complement restricted to `range`.
+    ///
+    /// The ranges of the result are the maximal gaps, which
+    /// `to_range_inclusive_vec` lists.
+    ///
+    /// ```rust
+    /// use char_iter::Ranges;
+    ///
+    /// let ranges = Ranges::from(&['b'..='c', 'x'..='z'][..]);
+    /// let gaps = ranges.gaps_within('a'..='y').to_range_inclusive_vec();
+    /// assert_eq!(gaps, ['a'..='a', 'd'..='w']);
+    /// ```
+    pub fn gaps_within<R: Into<Iter>>(&self, range: R) -> Ranges {
+        Ranges::from(range.into()).exclude(&self.runs())
+    }
+
+    /// The remaining characters as maximal inclusive ranges.
+    pub(crate) fn runs(&self) -> Vec<(char, char)> {
+        merge(self.ranges.iter().map(|r| (r.start, r.end)))
+    }
+
+    /// Keep only the characters that lie in one of the `allowed`
+    /// ranges, which must be inclusive, sorted and disjoint.
+    pub(crate) fn restrict(self, allowed: &[(char, char)]) -> Ranges {
+        let mut ranges = VecDeque::new();
+        let mut len = 0;
+        let mut i = 0;
+        for r in self.ranges {
+            while i < allowed.len() && allowed[i].1 < r.start {
+                i += 1;
+            }
+            // an allowed range can overlap several of ours, so `i`
+            // stays put and the next range starts looking from it.
+            let mut j = i;
+            while j < allowed.len() && allowed[j].0 <= r.end {
+                let piece = new(cmp::max(r.start, allowed[j].0),
+                                cmp::min(r.end, allowed[j].1));
+                len += piece.len();
+                ranges.push_back(piece);
+                j += 1;
+            }
+        }
+        Ranges {
+            ranges,
+            len,
+        }
+    }
+
+    /// Drop the characters that lie in one of the `excluded` ranges,
+    /// which must be inclusive, sorted and disjoint.
+    pub(crate) fn exclude(self, excluded: &[(char, char)]) -> Ranges {
+        let mut allowed = Vec::with_capacity(excluded.len() + 1);
+        // the first character not yet known to be excluded
+        let mut next = Some('\u{0}');
+        for &(lo, hi) in excluded {
+            if let Some(start) = next {
+                if start < lo {
+                    allowed.push((start, step(lo, Dir::Backward)));
+                }
+            }
+            next = if hi == '\u{10FFFF}' { None } else { Some(step(hi, Dir::Forward)) };
+        }
+        if let Some(start) = next {
+            allowed.push((start, '\u{10FFFF}'));
+        }
+        self.restrict(&allowed)
+    }
+}
+
+impl Iterator for Ranges {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let r = self.ranges.front_mut()?;
+        let (ret, done) = (r.next(), r.finished);
+        if done {
+            self.ranges.pop_front();
+        }
+        self.len -= 1;
+        ret
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+impl DoubleEndedIterator for Ranges {
+    fn next_back(&mut self) -> Option<char> {
+        let r = self.ranges.back_mut()?;
+        let (ret, done) = (r.next_back(), r.finished);
+        if done {
+            self.ranges.pop_back();
+        }
+        self.len -= 1;
+        ret
+    }
+}
+
+impl ExactSizeIterator for Ranges {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restrict() {
+        let allowed = [('a', 'c'), ('x', 'z'), ('\u{D7FF}', '\u{E001}')];
+        let iter = Ranges::from(new('b', '\u{E000}')).restrict(&allowed);
+        assert_eq!(iter.size_hint(), (7, Some(7)));
+        let v: Vec<char> = iter.collect();
+        assert_eq!(v, &['b', 'c', 'x', 'y', 'z', '\u{D7FF}', '\u{E000}']);
+    }
+    #[test]
+    fn restrict_twice() {
+        let iter = Ranges::from(new('a', 'z'))
+            .restrict(&[('a', 'e'), ('m', 'p')])
+            .restrict(&[('d', 'n')]);
+        let v: Vec<char> = iter.rev().collect();
+        assert_eq!(v, &['n', 'm', 'e', 'd']);
+    }
+    #[test]
+    fn restrict_disjoint() {
+        let iter = Ranges::from(new('a', 'z')).restrict(&[('0', '9')]);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.count(), 0);
+    }
+    #[test]
+    fn exclude() {
+        let iter = Ranges::from(new('\u{0}', '\u{10FFFF}'))
+            .exclude(&[('\u{0}', 'a'), ('c', '\u{D7FF}'), ('\u{E001}', '\u{10FFFF}')]);
+        let v: Vec<char> = iter.collect();
+        assert_eq!(v, &['b', '\u{E000}']);
+    }
+    #[test]
+    fn exclude_nothing() {
+        let iter = Ranges::from(new('a', 'z')).exclude(&[]);
+        assert_eq!(iter.len(), 26);
+    }
+    #[test]
+    fn from_slice() {
+        let ranges = Ranges::from(&['\u{E000}'..='\u{E001}', 'b'..='a', '\u{D7FE}'..='\u{D7FF}', 'a'..='c'][..]);
+        assert_eq!(ranges.len(), 7);
+        assert_eq!(ranges.to_range_inclusive_vec(), ['a'..='c', '\u{D7FE}'..='\u{E001}']);
+        let all = Ranges::from(&['\u{10FFFF}'..='\u{10FFFF}', '\u{0}'..='\u{10FFFF}'][..]);
+        assert_eq!(all.to_range_inclusive_vec(), ['\u{0}'..='\u{10FFFF}']);
+        assert_eq!(Ranges::from(&[][..]).to_range_inclusive_vec(), []);
+    }
+    #[test]
+    fn to_range_inclusive_vec_partial() {
+        let mut ranges = Ranges::from(new('a', 'z')).restrict(&[('a', 'b'), ('c', 'd'), ('y', 'z')]);
+        ranges.next();
+        ranges.next_back();
+        assert_eq!(ranges.to_range_inclusive_vec(), ['b'..='d', 'y'..='y']);
+    }
+    #[test]
+    fn member_bounds() {
+        let mut ranges = Ranges::from(new('a', 'z')).restrict(&[('b', 'd'), ('m', 'm'), ('x', 'y')]);
+        let after: String = new('a', 'z').filter_map(|c| ranges.next_member_at_or_after(c)).collect();
+        assert_eq!(after, "bbcdmmmmmmmmmxxxxxxxxxxxy");
+        let before: String = new('a', 'z').filter_map(|c| ranges.prev_member_at_or_before(c)).collect();
+        assert_eq!(before, "bcdddddddddmmmmmmmmmmmxyy");
+        // only the remaining characters count
+        ranges.next();
+        ranges.next_back();
+        assert_eq!(ranges.next_member_at_or_after('a'), Some('c'));
+        assert_eq!(ranges.prev_member_at_or_before('z'), Some('x'));
+        let ranges = Ranges::from(new('\u{D7FF}', '\u{E000}'));
+        assert_eq!(ranges.next_member_at_or_after('\u{E000}'), Some('\u{E000}'));
+        assert_eq!(ranges.prev_member_at_or_before('\u{10FFFF}'), Some('\u{E000}'));
+    }
+    #[test]
+    fn gaps_within() {
+        let mut ranges = Ranges::from(new('a', 'z')).restrict(&[('a', 'b'), ('c', 'd'), ('y', 'z')]);
+        let gaps = ranges.gaps_within(new('a', 'z'));
+        assert_eq!(gaps.ranges.len(), 1);
+        assert_eq!(gaps.collect::<String>(), "efghijklmnopqrstuvwx");
+        ranges.next();
+        assert_eq!(ranges.gaps_within('a'..='c').to_range_inclusive_vec(), ['a'..='a']);
+        assert_eq!(ranges.gaps_within('b'..='d').len(), 0);
+        let ranges = Ranges::from(&['\u{0}'..='\u{D7FE}', '\u{E001}'..='\u{10FFFF}'][..]);
+        assert_eq!(ranges.gaps_within('\u{0}'..='\u{10FFFF}').to_range_inclusive_vec(),
+                   ['\u{D7FF}'..='\u{E000}']);
+    }
+    #[test]
+    fn size_hint() {
+        let mut iter = Ranges::from(new('a', 'z')).restrict(&[('a', 'b'), ('y', 'z')]);
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        for i in (0..4).rev() {
+            if i % 2 == 0 { iter.next(); } else { iter.next_back(); }
+            assert_eq!(iter.size_hint(), (i, Some(i)));
+        }
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+    }
+}
@@ -0,0 +1,229 @@
+//! Iteration over raw code point and byte values.
+
+use core::ops::RangeInclusive;
+
+use {AFTER_SUR, BEFORE_SUR, SUR_END, SUR_START};
+
+/// An iterator over a range of code points as `u32`s.
+///
+/// This is constructed by the `codepoints` and
+/// `codepoints_with_surrogates` functions at the top level.
+#[derive(Clone)]
+pub struct Codepoints {
+    start: u32,
+    end: u32,
+    finished: bool,
+    surrogates: bool,
+}
+
+fn is_surrogate(val: u32) -> bool {
+    (SUR_START..=SUR_END).contains(&val)
+}
+
+fn codepoints_(start: u32, end: u32, surrogates: bool) -> Codepoints {
+    assert!(start <= end);
+    assert!(end <= 0x10FFFF);
+    let (start, end) = if surrogates {
+        (start, end)
+    } else {
+        (if is_surrogate(start) {AFTER_SUR} else {start},
+         if is_surrogate(end) {BEFORE_SUR} else {end})
+    };
+    Codepoints {
+        start,
+        end,
+        finished: start > end,
+        surrogates
+    }
+}
+
+/// Create a new iterator over the code points from `start` to `end`,
+/// inclusive, as `u32`s, skipping the surrogate range like `new`.
+///
+/// ```rust
+/// let v: Vec<u32> = char_iter::codepoints(0xD7FE, 0xE001).collect();
+/// assert_eq!(v, [0xD7FE, 0xD7FF, 0xE000, 0xE001]);
+/// ```
+///
+/// # Panics
+///
+/// This panics if `start > end` or if `end` is past `0x10FFFF`.
+pub fn codepoints(start: u32, end: u32) -> Codepoints {
+    codepoints_(start, end, false)
+}
+
+/// Create a new iterator over the code points from `start` to `end`,
+/// inclusive, as `u32`s, including the surrogate range.
+///
+/// ```rust
+/// let v = char_iter::codepoints_with_surrogates(0xD7FF, 0xE000);
+/// assert_eq!(v.len(), 2 + 2048);
+/// ```
+///
+/// # Panics
+///
+/// This panics if `start > end` or if `end` is past `0x10FFFF`.
+pub fn codepoints_with_surrogates(start: u32, end: u32) -> Codepoints {
+    codepoints_(start, end, true)
+}
+
+impl Iterator for Codepoints {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        if self.finished {
+            return None
+        }
+        let ret = Some(self.start);
+        if self.start == self.end {
+            self.finished = true;
+        } else if !self.surrogates && self.start == BEFORE_SUR {
+            self.start = AFTER_SUR;
+        } else {
+            self.start += 1;
+        }
+        ret
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = if self.finished {
+            0
+        } else {
+            let naive_count = (self.end - self.start + 1) as usize;
+            if !self.surrogates && self.start <= BEFORE_SUR && self.end >= AFTER_SUR {
+                naive_count - (SUR_END - SUR_START + 1) as usize
+            } else {
+                naive_count
+            }
+        };
+        (len, Some(len))
+    }
+}
+impl DoubleEndedIterator for Codepoints {
+    fn next_back(&mut self) -> Option<u32> {
+        if self.finished {
+            return None
+        }
+        let ret = Some(self.end);
+        if self.start == self.end {
+            self.finished = true;
+        } else if !self.surrogates && self.end == AFTER_SUR {
+            self.end = BEFORE_SUR;
+        } else {
+            self.end -= 1;
+        }
+        ret
+    }
+}
+
+impl ExactSizeIterator for Codepoints {}
+
+/// An iterator over a range of ASCII bytes.
+///
+/// This is constructed by the `ascii` function at the top level.
+#[derive(Clone)]
+pub struct Ascii {
+    inner: RangeInclusive<u8>,
+}
+
+/// Create a new iterator over the ASCII bytes from `start` to `end`,
+/// inclusive.
+///
+/// ```rust
+/// let v: Vec<u8> = char_iter::ascii(b'a', b'e').rev().collect();
+/// assert_eq!(v, b"edcba");
+/// ```
+///
+/// # Panics
+///
+/// This panics if `start > end` or if `end` is not ASCII.
+pub fn ascii(start: u8, end: u8) -> Ascii {
+    assert!(start <= end);
+    assert!(end.is_ascii());
+    Ascii { inner: start..=end }
+}
+
+impl Iterator for Ascii {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<u8> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+impl DoubleEndedIterator for Ascii {
+    fn next_back(&mut self) -> Option<u8> {
+        self.inner.next_back()
+    }
+}
+
+impl ExactSizeIterator for Ascii {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn smoke() {
+        let v: Vec<u32> = codepoints(0x61, 0x63).collect();
+        assert_eq!(v, [0x61, 0x62, 0x63]);
+        let v: Vec<u32> = codepoints(0x61, 0x63).rev().collect();
+        assert_eq!(v, [0x63, 0x62, 0x61]);
+    }
+    #[test]
+    fn surrogates() {
+        let v: Vec<u32> = codepoints(0xD7FF, 0xE000).rev().collect();
+        assert_eq!(v, [0xE000, 0xD7FF]);
+        assert_eq!(codepoints(0xD800, 0xDFFF).count(), 0);
+        assert_eq!(codepoints(0xD900, 0xE000).collect::<Vec<_>>(), [0xE000]);
+        assert_eq!(codepoints(0xD7FF, 0xD900).collect::<Vec<_>>(), [0xD7FF]);
+
+        let iter = codepoints_with_surrogates(0xD7FF, 0xE000);
+        assert!(iter.eq(0xD7FF..0xE001));
+    }
+    #[test]
+    fn full_range() {
+        let iter = codepoints(0, 0x10FFFF);
+        assert_eq!(iter.len(), 1_114_112 - 2048);
+        assert!(iter.eq(::new('\u{0}', '\u{10FFFF}').map(|c| c as u32)));
+        let mut iter = codepoints_with_surrogates(0, 0x10FFFF);
+        assert_eq!(iter.len(), 1_114_112);
+        iter.next();
+        iter.next_back();
+        assert_eq!(iter.len(), 1_114_110);
+    }
+    #[should_panic]
+    #[test]
+    fn invalid() {
+        codepoints(0x62, 0x61);
+    }
+    #[should_panic]
+    #[test]
+    fn too_large() {
+        codepoints(0, 0x110000);
+    }
+
+    #[test]
+    fn ascii_smoke() {
+        let mut iter = ascii(0, 0x7F);
+        assert_eq!(iter.len(), 128);
+        assert_eq!(iter.next(), Some(0));
+        assert_eq!(iter.next_back(), Some(0x7F));
+        assert_eq!(iter.len(), 126);
+        assert!(iter.eq(1..0x7F));
+        assert_eq!(ascii(b'a', b'a').collect::<Vec<_>>(), b"a");
+    }
+    #[should_panic]
+    #[test]
+    fn ascii_invalid() {
+        ascii(b'b', b'a');
+    }
+    #[should_panic]
+    #[test]
+    fn ascii_not_ascii() {
+        ascii(0, 0x80);
+    }
+}
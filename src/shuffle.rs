@@ -0,0 +1,226 @@
+//! Visiting a range in a permuted order.
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use forward;
+use Iter;
+
+const ROUNDS: usize = 4;
+
+/// An iterator over the characters of a range in a permuted order,
+/// visiting each exactly once.
+///
+/// The order is a bijection on the indices of the range: a small
+/// Feistel network over the next power of four, walking its cycles
+/// until an index lands inside the range. This takes constant memory
+/// and a few mixing rounds per character.
+///
+/// This is constructed by `Iter::permuted`, or `Iter::shuffled` with
+/// the `rand` feature.
+pub struct Shuffled {
+    start: char,
+    len: u32,
+    half_bits: u32,
+    keys: [u64; ROUNDS],
+    front: u32,
+    back: u32,
+}
+
+/// A 64-bit finalizer (from SplitMix64), used as the Feistel round
+/// function.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^ (x >> 31)
+}
+
+impl Shuffled {
+    pub(crate) fn new(iter: Iter, keys: [u64; ROUNDS]) -> Shuffled {
+        let len = iter.len() as u32;
+        let mut half_bits = 1;
+        while 1u64 << (2 * half_bits) < len as u64 {
+            half_bits += 1;
+        }
+        Shuffled {
+            start: iter.start,
+            len,
+            half_bits,
+            keys,
+            front: 0,
+            back: len,
+        }
+    }
+
+    /// One pass of the Feistel network, a bijection on
+    /// `0..4^half_bits`.
+    fn feistel(&self, x: u32) -> u32 {
+        let mask = (1 << self.half_bits) - 1;
+        let (mut l, mut r) = (x >> self.half_bits, x & mask);
+        for &key in &self.keys {
+            let f = mix(r as u64 ^ key) as u32 & mask;
+            let next = l ^ f;
+            l = r;
+            r = next;
+        }
+        (l << self.half_bits) | r
+    }
+
+    /// The index visited at position `i` of the order.
+    fn permute(&self, i: u32) -> u32 {
+        let mut x = self.feistel(i);
+        while x >= self.len {
+            x = self.feistel(x);
+        }
+        x
+    }
+}
+
+impl Iter {
+    /// Yield every remaining character exactly once, in a
+    /// pseudo-random order determined entirely by `seed`.
+    ///
+    /// The same seed and range always give the same order, on every
+    /// platform and in every run, so a randomized exhaustive sweep can
+    /// be reproduced exactly. Nothing is buffered.
+    ///
+    /// ```rust
+    /// let a: Vec<char> = char_iter::new('a', 'z').permuted(42).collect();
+    /// let b: Vec<char> = char_iter::new('a', 'z').permuted(42).collect();
+    /// assert_eq!(a, b);
+    ///
+    /// let mut sorted = a.clone();
+    /// sorted.sort();
+    /// assert_eq!(sorted, char_iter::new('a', 'z').collect::<Vec<_>>());
+    /// ```
+    pub fn permuted(self, seed: u64) -> Shuffled {
+        let mut keys = [0; ROUNDS];
+        for (i, key) in keys.iter_mut().enumerate() {
+            *key = mix(seed.wrapping_add((i as u64 + 1).wrapping_mul(0x9E3779B97F4A7C15)));
+        }
+        Shuffled::new(self, keys)
+    }
+
+    /// Yield every remaining character exactly once, in a random
+    /// order.
+    ///
+    /// Nothing is buffered: the order comes from a random permutation
+    /// of the indices into the range, evaluated one index at a time.
+    /// The permutation is a keyed mixing function, which is plenty
+    /// for flushing out order dependence but is not a perfectly
+    /// uniform shuffle.
+    ///
+    /// ```rust
+    /// extern crate char_iter;
+    /// extern crate rand;
+    ///
+    /// # fn main() {
+    /// let mut v: Vec<char> = char_iter::new('a', 'z').shuffled(&mut rand::rng()).collect();
+    /// v.sort();
+    /// assert_eq!(v, char_iter::new('a', 'z').collect::<Vec<_>>());
+    /// # }
+    /// ```
+    #[cfg(feature = "rand")]
+    pub fn shuffled<R: Rng + ?Sized>(self, rng: &mut R) -> Shuffled {
+        Shuffled::new(self, [rng.random(), rng.random(), rng.random(), rng.random()])
+    }
+}
+
+impl Iterator for Shuffled {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        if self.front == self.back {
+            return None
+        }
+        let i = self.permute(self.front);
+        self.front += 1;
+        Some(forward(self.start, i))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.back - self.front) as usize;
+        (len, Some(len))
+    }
+}
+impl DoubleEndedIterator for Shuffled {
+    fn next_back(&mut self) -> Option<char> {
+        if self.front == self.back {
+            return None
+        }
+        self.back -= 1;
+        Some(forward(self.start, self.permute(self.back)))
+    }
+}
+
+impl ExactSizeIterator for Shuffled {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "rand")]
+    use rand::rngs::SmallRng;
+    #[cfg(feature = "rand")]
+    use rand::SeedableRng;
+    use super::*;
+    use new;
+
+    fn check_permutation(start: char, end: char, keys: [u64; ROUNDS]) {
+        let mut v: Vec<char> = Shuffled::new(new(start, end), keys).collect();
+        v.sort();
+        assert_eq!(v, new(start, end).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn permutation() {
+        check_permutation('a', 'a', [1, 2, 3, 4]);
+        check_permutation('a', 'b', [1, 2, 3, 4]);
+        check_permutation('a', 'z', [5, 6, 7, 8]);
+        check_permutation('\u{D000}', '\u{E100}', [0, 0, 0, 0]);
+    }
+    #[test]
+    fn full_range() {
+        let mut seen = vec![false; 0x110000];
+        let iter = Shuffled::new(new('\u{0}', '\u{10FFFF}'), [9, 10, 11, 12]);
+        assert_eq!(iter.len(), 1_114_112 - 2048);
+        for c in iter {
+            assert!(!seen[c as usize]);
+            seen[c as usize] = true;
+        }
+        assert_eq!(seen.iter().filter(|&&b| b).count(), 1_114_112 - 2048);
+    }
+    #[test]
+    fn rev() {
+        let forwards: Vec<char> = Shuffled::new(new('a', 'z'), [1, 2, 3, 4]).collect();
+        let mut backwards: Vec<char> = Shuffled::new(new('a', 'z'), [1, 2, 3, 4]).rev().collect();
+        backwards.reverse();
+        assert_eq!(forwards, backwards);
+    }
+    #[test]
+    fn permuted_is_stable() {
+        // changing this order breaks reproducibility for users
+        let v: String = new('a', 'p').permuted(0).collect();
+        assert_eq!(v, "opgnkmbjeflcdhia");
+        let other: String = new('a', 'p').permuted(1).collect();
+        assert!(v != other);
+    }
+    #[test]
+    fn empty() {
+        let mut iter = new('a', 'a');
+        iter.next();
+        let mut shuffled = Shuffled::new(iter, [1, 2, 3, 4]);
+        assert_eq!(shuffled.len(), 0);
+        assert_eq!(shuffled.next(), None);
+    }
+    #[cfg(feature = "rand")]
+    #[test]
+    fn shuffled() {
+        let mut rng = SmallRng::seed_from_u64(2);
+        let iter = new('\u{D7F0}', '\u{E00F}').shuffled(&mut rng);
+        assert_eq!(iter.len(), 32);
+        let v: Vec<char> = iter.collect();
+        assert!(v != new('\u{D7F0}', '\u{E00F}').collect::<Vec<_>>());
+        let mut sorted = v.clone();
+        sorted.sort();
+        assert_eq!(sorted, new('\u{D7F0}', '\u{E00F}').collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,99 @@
+//! Moving between characters, jumping over the surrogate range.
+
+use core::cmp;
+
+use {scalar, step, Dir, AFTER_SUR, BEFORE_SUR, SUR_END, SUR_START};
+
+/// The number of surrogate code points.
+const SUR_LEN: u32 = SUR_END - SUR_START + 1;
+
+/// The position of `c` among all the Unicode scalar values.
+fn index(c: char) -> u32 {
+    let val = c as u32;
+    if val >= AFTER_SUR { val - SUR_LEN } else { val }
+}
+
+/// The character after `c`, skipping the surrogate range, or `None`
+/// if `c` is `'\u{10FFFF}'`.
+///
+/// ```rust
+/// assert_eq!(char_iter::next_char('a'), Some('b'));
+/// assert_eq!(char_iter::next_char('\u{D7FF}'), Some('\u{E000}'));
+/// assert_eq!(char_iter::next_char('\u{10FFFF}'), None);
+/// ```
+pub fn next_char(c: char) -> Option<char> {
+    if c == '\u{10FFFF}' { None } else { Some(step(c, Dir::Forward)) }
+}
+
+/// The character before `c`, skipping the surrogate range, or `None`
+/// if `c` is `'\0'`.
+///
+/// ```rust
+/// assert_eq!(char_iter::prev_char('b'), Some('a'));
+/// assert_eq!(char_iter::prev_char('\u{E000}'), Some('\u{D7FF}'));
+/// assert_eq!(char_iter::prev_char('\0'), None);
+/// ```
+pub fn prev_char(c: char) -> Option<char> {
+    if c == '\0' { None } else { Some(step(c, Dir::Backward)) }
+}
+
+/// The number of steps between `a` and `b`, in either order, not
+/// counting the surrogate range.
+///
+/// This is one less than the length of `new(a, b)` (or `new(b, a)`).
+///
+/// ```rust
+/// assert_eq!(char_iter::distance('a', 'z'), 25);
+/// assert_eq!(char_iter::distance('z', 'a'), 25);
+/// assert_eq!(char_iter::distance('\u{D7FF}', '\u{E000}'), 1);
+/// ```
+pub fn distance(a: char, b: char) -> u32 {
+    let (a, b) = (index(a), index(b));
+    cmp::max(a, b) - cmp::min(a, b)
+}
+
+/// The character `n` steps after `c` (or before, if `n` is
+/// negative), skipping the surrogate range, or `None` if that would
+/// go outside `'\0'` to `'\u{10FFFF}'`.
+///
+/// ```rust
+/// assert_eq!(char_iter::offset('a', 2), Some('c'));
+/// assert_eq!(char_iter::offset('\u{E000}', -2), Some('\u{D7FE}'));
+/// assert_eq!(char_iter::offset('a', -100), None);
+/// ```
+pub fn offset(c: char, n: i32) -> Option<char> {
+    let idx = index(c) as i64 + n as i64;
+    if idx < 0 || idx > index('\u{10FFFF}') as i64 {
+        return None
+    }
+    let idx = idx as u32;
+    Some(scalar(if idx > BEFORE_SUR { idx + SUR_LEN } else { idx }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use new;
+
+    #[test]
+    fn next_prev() {
+        for (a, b) in new('\u{0}', '\u{10FFFF}').zip(new('\u{1}', '\u{10FFFF}')) {
+            assert_eq!(next_char(a), Some(b));
+            assert_eq!(prev_char(b), Some(a));
+        }
+    }
+    #[test]
+    fn distance_offset() {
+        let full = new('\u{0}', '\u{10FFFF}');
+        assert_eq!(distance('\u{0}', '\u{10FFFF}') as usize, full.len() - 1);
+        for (i, c) in full.enumerate().step_by(997) {
+            assert_eq!(distance('\u{0}', c), i as u32);
+            assert_eq!(offset('\u{0}', i as i32), Some(c));
+            assert_eq!(offset(c, -(i as i32)), Some('\u{0}'));
+        }
+        assert_eq!(offset('\u{10FFFF}', 1), None);
+        assert_eq!(offset('\u{10FFFF}', 0), Some('\u{10FFFF}'));
+        assert_eq!(offset('\u{0}', i32::MIN), None);
+        assert_eq!(offset('\u{0}', i32::MAX), None);
+    }
+}
@@ -0,0 +1,71 @@
+//! Using ranges of characters as asynchronous streams.
+//!
+//! Both kinds of range are always ready, so they can be polled
+//! directly, without a wrapper type.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use futures_core::Stream;
+
+use Iter;
+#[cfg(feature = "alloc")]
+use Ranges;
+
+impl Stream for Iter {
+    type Item = char;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Option<char>> {
+        Poll::Ready(self.get_mut().next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Stream for Ranges {
+    type Item = char;
+
+    fn poll_next(self: Pin<&mut Self>, _cx: &mut Context) -> Poll<Option<char>> {
+        Poll::Ready(self.get_mut().next())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        Iterator::size_hint(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use futures_core::Stream;
+    use futures_task::noop_waker_ref;
+
+    use new;
+
+    fn collect<S: Stream<Item = char> + Unpin>(mut s: S) -> Vec<char> {
+        let mut cx = Context::from_waker(noop_waker_ref());
+        let mut v = vec![];
+        while let Poll::Ready(Some(c)) = Pin::new(&mut s).poll_next(&mut cx) {
+            v.push(c);
+        }
+        v
+    }
+
+    #[test]
+    fn iter() {
+        let iter = new('\u{D7FE}', '\u{E001}');
+        assert_eq!(Stream::size_hint(&iter), (4, Some(4)));
+        assert_eq!(collect(iter), ['\u{D7FE}', '\u{D7FF}', '\u{E000}', '\u{E001}']);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ranges() {
+        let ranges = new('\u{FFFD}', '\u{FFFF}').skip_noncharacters();
+        assert_eq!(collect(ranges), ['\u{FFFD}']);
+    }
+}
@@ -0,0 +1,1578 @@
+//! Generated tables of Unicode data.
+
+// Generated from the Unicode Character Database's Blocks.txt, version 17.0.0.
+// The three surrogate blocks are omitted, since no `char` lies in them.
+
+use self::Block::*;
+
+/// A Unicode block: a named, contiguous range of code points.
+///
+/// Blocks are listed in code point order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Block {
+    /// Basic Latin (`U+0000`..=`U+007F`)
+    BasicLatin,
+    /// Latin-1 Supplement (`U+0080`..=`U+00FF`)
+    Latin1Supplement,
+    /// Latin Extended-A (`U+0100`..=`U+017F`)
+    LatinExtendedA,
+    /// Latin Extended-B (`U+0180`..=`U+024F`)
+    LatinExtendedB,
+    /// IPA Extensions (`U+0250`..=`U+02AF`)
+    IpaExtensions,
+    /// Spacing Modifier Letters (`U+02B0`..=`U+02FF`)
+    SpacingModifierLetters,
+    /// Combining Diacritical Marks (`U+0300`..=`U+036F`)
+    CombiningDiacriticalMarks,
+    /// Greek and Coptic (`U+0370`..=`U+03FF`)
+    GreekAndCoptic,
+    /// Cyrillic (`U+0400`..=`U+04FF`)
+    Cyrillic,
+    /// Cyrillic Supplement (`U+0500`..=`U+052F`)
+    CyrillicSupplement,
+    /// Armenian (`U+0530`..=`U+058F`)
+    Armenian,
+    /// Hebrew (`U+0590`..=`U+05FF`)
+    Hebrew,
+    /// Arabic (`U+0600`..=`U+06FF`)
+    Arabic,
+    /// Syriac (`U+0700`..=`U+074F`)
+    Syriac,
+    /// Arabic Supplement (`U+0750`..=`U+077F`)
+    ArabicSupplement,
+    /// Thaana (`U+0780`..=`U+07BF`)
+    Thaana,
+    /// NKo (`U+07C0`..=`U+07FF`)
+    Nko,
+    /// Samaritan (`U+0800`..=`U+083F`)
+    Samaritan,
+    /// Mandaic (`U+0840`..=`U+085F`)
+    Mandaic,
+    /// Syriac Supplement (`U+0860`..=`U+086F`)
+    SyriacSupplement,
+    /// Arabic Extended-B (`U+0870`..=`U+089F`)
+    ArabicExtendedB,
+    /// Arabic Extended-A (`U+08A0`..=`U+08FF`)
+    ArabicExtendedA,
+    /// Devanagari (`U+0900`..=`U+097F`)
+    Devanagari,
+    /// Bengali (`U+0980`..=`U+09FF`)
+    Bengali,
+    /// Gurmukhi (`U+0A00`..=`U+0A7F`)
+    Gurmukhi,
+    /// Gujarati (`U+0A80`..=`U+0AFF`)
+    Gujarati,
+    /// Oriya (`U+0B00`..=`U+0B7F`)
+    Oriya,
+    /// Tamil (`U+0B80`..=`U+0BFF`)
+    Tamil,
+    /// Telugu (`U+0C00`..=`U+0C7F`)
+    Telugu,
+    /// Kannada (`U+0C80`..=`U+0CFF`)
+    Kannada,
+    /// Malayalam (`U+0D00`..=`U+0D7F`)
+    Malayalam,
+    /// Sinhala (`U+0D80`..=`U+0DFF`)
+    Sinhala,
+    /// Thai (`U+0E00`..=`U+0E7F`)
+    Thai,
+    /// Lao (`U+0E80`..=`U+0EFF`)
+    Lao,
+    /// Tibetan (`U+0F00`..=`U+0FFF`)
+    Tibetan,
+    /// Myanmar (`U+1000`..=`U+109F`)
+    Myanmar,
+    /// Georgian (`U+10A0`..=`U+10FF`)
+    Georgian,
+    /// Hangul Jamo (`U+1100`..=`U+11FF`)
+    HangulJamo,
+    /// Ethiopic (`U+1200`..=`U+137F`)
+    Ethiopic,
+    /// Ethiopic Supplement (`U+1380`..=`U+139F`)
+    EthiopicSupplement,
+    /// Cherokee (`U+13A0`..=`U+13FF`)
+    Cherokee,
+    /// Unified Canadian Aboriginal Syllabics (`U+1400`..=`U+167F`)
+    UnifiedCanadianAboriginalSyllabics,
+    /// Ogham (`U+1680`..=`U+169F`)
+    Ogham,
+    /// Runic (`U+16A0`..=`U+16FF`)
+    Runic,
+    /// Tagalog (`U+1700`..=`U+171F`)
+    Tagalog,
+    /// Hanunoo (`U+1720`..=`U+173F`)
+    Hanunoo,
+    /// Buhid (`U+1740`..=`U+175F`)
+    Buhid,
+    /// Tagbanwa (`U+1760`..=`U+177F`)
+    Tagbanwa,
+    /// Khmer (`U+1780`..=`U+17FF`)
+    Khmer,
+    /// Mongolian (`U+1800`..=`U+18AF`)
+    Mongolian,
+    /// Unified Canadian Aboriginal Syllabics Extended (`U+18B0`..=`U+18FF`)
+    UnifiedCanadianAboriginalSyllabicsExtended,
+    /// Limbu (`U+1900`..=`U+194F`)
+    Limbu,
+    /// Tai Le (`U+1950`..=`U+197F`)
+    TaiLe,
+    /// New Tai Lue (`U+1980`..=`U+19DF`)
+    NewTaiLue,
+    /// Khmer Symbols (`U+19E0`..=`U+19FF`)
+    KhmerSymbols,
+    /// Buginese (`U+1A00`..=`U+1A1F`)
+    Buginese,
+    /// Tai Tham (`U+1A20`..=`U+1AAF`)
+    TaiTham,
+    /// Combining Diacritical Marks Extended (`U+1AB0`..=`U+1AFF`)
+    CombiningDiacriticalMarksExtended,
+    /// Balinese (`U+1B00`..=`U+1B7F`)
+    Balinese,
+    /// Sundanese (`U+1B80`..=`U+1BBF`)
+    Sundanese,
+    /// Batak (`U+1BC0`..=`U+1BFF`)
+    Batak,
+    /// Lepcha (`U+1C00`..=`U+1C4F`)
+    Lepcha,
+    /// Ol Chiki (`U+1C50`..=`U+1C7F`)
+    OlChiki,
+    /// Cyrillic Extended-C (`U+1C80`..=`U+1C8F`)
+    CyrillicExtendedC,
+    /// Georgian Extended (`U+1C90`..=`U+1CBF`)
+    GeorgianExtended,
+    /// Sundanese Supplement (`U+1CC0`..=`U+1CCF`)
+    SundaneseSupplement,
+    /// Vedic Extensions (`U+1CD0`..=`U+1CFF`)
+    VedicExtensions,
+    /// Phonetic Extensions (`U+1D00`..=`U+1D7F`)
+    PhoneticExtensions,
+    /// Phonetic Extensions Supplement (`U+1D80`..=`U+1DBF`)
+    PhoneticExtensionsSupplement,
+    /// Combining Diacritical Marks Supplement (`U+1DC0`..=`U+1DFF`)
+    CombiningDiacriticalMarksSupplement,
+    /// Latin Extended Additional (`U+1E00`..=`U+1EFF`)
+    LatinExtendedAdditional,
+    /// Greek Extended (`U+1F00`..=`U+1FFF`)
+    GreekExtended,
+    /// General Punctuation (`U+2000`..=`U+206F`)
+    GeneralPunctuation,
+    /// Superscripts and Subscripts (`U+2070`..=`U+209F`)
+    SuperscriptsAndSubscripts,
+    /// Currency Symbols (`U+20A0`..=`U+20CF`)
+    CurrencySymbols,
+    /// Combining Diacritical Marks for Symbols (`U+20D0`..=`U+20FF`)
+    CombiningDiacriticalMarksForSymbols,
+    /// Letterlike Symbols (`U+2100`..=`U+214F`)
+    LetterlikeSymbols,
+    /// Number Forms (`U+2150`..=`U+218F`)
+    NumberForms,
+    /// Arrows (`U+2190`..=`U+21FF`)
+    Arrows,
+    /// Mathematical Operators (`U+2200`..=`U+22FF`)
+    MathematicalOperators,
+    /// Miscellaneous Technical (`U+2300`..=`U+23FF`)
+    MiscellaneousTechnical,
+    /// Control Pictures (`U+2400`..=`U+243F`)
+    ControlPictures,
+    /// Optical Character Recognition (`U+2440`..=`U+245F`)
+    OpticalCharacterRecognition,
+    /// Enclosed Alphanumerics (`U+2460`..=`U+24FF`)
+    EnclosedAlphanumerics,
+    /// Box Drawing (`U+2500`..=`U+257F`)
+    BoxDrawing,
+    /// Block Elements (`U+2580`..=`U+259F`)
+    BlockElements,
+    /// Geometric Shapes (`U+25A0`..=`U+25FF`)
+    GeometricShapes,
+    /// Miscellaneous Symbols (`U+2600`..=`U+26FF`)
+    MiscellaneousSymbols,
+    /// Dingbats (`U+2700`..=`U+27BF`)
+    Dingbats,
+    /// Miscellaneous Mathematical Symbols-A (`U+27C0`..=`U+27EF`)
+    MiscellaneousMathematicalSymbolsA,
+    /// Supplemental Arrows-A (`U+27F0`..=`U+27FF`)
+    SupplementalArrowsA,
+    /// Braille Patterns (`U+2800`..=`U+28FF`)
+    BraillePatterns,
+    /// Supplemental Arrows-B (`U+2900`..=`U+297F`)
+    SupplementalArrowsB,
+    /// Miscellaneous Mathematical Symbols-B (`U+2980`..=`U+29FF`)
+    MiscellaneousMathematicalSymbolsB,
+    /// Supplemental Mathematical Operators (`U+2A00`..=`U+2AFF`)
+    SupplementalMathematicalOperators,
+    /// Miscellaneous Symbols and Arrows (`U+2B00`..=`U+2BFF`)
+    MiscellaneousSymbolsAndArrows,
+    /// Glagolitic (`U+2C00`..=`U+2C5F`)
+    Glagolitic,
+    /// Latin Extended-C (`U+2C60`..=`U+2C7F`)
+    LatinExtendedC,
+    /// Coptic (`U+2C80`..=`U+2CFF`)
+    Coptic,
+    /// Georgian Supplement (`U+2D00`..=`U+2D2F`)
+    GeorgianSupplement,
+    /// Tifinagh (`U+2D30`..=`U+2D7F`)
+    Tifinagh,
+    /// Ethiopic Extended (`U+2D80`..=`U+2DDF`)
+    EthiopicExtended,
+    /// Cyrillic Extended-A (`U+2DE0`..=`U+2DFF`)
+    CyrillicExtendedA,
+    /// Supplemental Punctuation (`U+2E00`..=`U+2E7F`)
+    SupplementalPunctuation,
+    /// CJK Radicals Supplement (`U+2E80`..=`U+2EFF`)
+    CjkRadicalsSupplement,
+    /// Kangxi Radicals (`U+2F00`..=`U+2FDF`)
+    KangxiRadicals,
+    /// Ideographic Description Characters (`U+2FF0`..=`U+2FFF`)
+    IdeographicDescriptionCharacters,
+    /// CJK Symbols and Punctuation (`U+3000`..=`U+303F`)
+    CjkSymbolsAndPunctuation,
+    /// Hiragana (`U+3040`..=`U+309F`)
+    Hiragana,
+    /// Katakana (`U+30A0`..=`U+30FF`)
+    Katakana,
+    /// Bopomofo (`U+3100`..=`U+312F`)
+    Bopomofo,
+    /// Hangul Compatibility Jamo (`U+3130`..=`U+318F`)
+    HangulCompatibilityJamo,
+    /// Kanbun (`U+3190`..=`U+319F`)
+    Kanbun,
+    /// Bopomofo Extended (`U+31A0`..=`U+31BF`)
+    BopomofoExtended,
+    /// CJK Strokes (`U+31C0`..=`U+31EF`)
+    CjkStrokes,
+    /// Katakana Phonetic Extensions (`U+31F0`..=`U+31FF`)
+    KatakanaPhoneticExtensions,
+    /// Enclosed CJK Letters and Months (`U+3200`..=`U+32FF`)
+    EnclosedCjkLettersAndMonths,
+    /// CJK Compatibility (`U+3300`..=`U+33FF`)
+    CjkCompatibility,
+    /// CJK Unified Ideographs Extension A (`U+3400`..=`U+4DBF`)
+    CjkUnifiedIdeographsExtensionA,
+    /// Yijing Hexagram Symbols (`U+4DC0`..=`U+4DFF`)
+    YijingHexagramSymbols,
+    /// CJK Unified Ideographs (`U+4E00`..=`U+9FFF`)
+    CjkUnifiedIdeographs,
+    /// Yi Syllables (`U+A000`..=`U+A48F`)
+    YiSyllables,
+    /// Yi Radicals (`U+A490`..=`U+A4CF`)
+    YiRadicals,
+    /// Lisu (`U+A4D0`..=`U+A4FF`)
+    Lisu,
+    /// Vai (`U+A500`..=`U+A63F`)
+    Vai,
+    /// Cyrillic Extended-B (`U+A640`..=`U+A69F`)
+    CyrillicExtendedB,
+    /// Bamum (`U+A6A0`..=`U+A6FF`)
+    Bamum,
+    /// Modifier Tone Letters (`U+A700`..=`U+A71F`)
+    ModifierToneLetters,
+    /// Latin Extended-D (`U+A720`..=`U+A7FF`)
+    LatinExtendedD,
+    /// Syloti Nagri (`U+A800`..=`U+A82F`)
+    SylotiNagri,
+    /// Common Indic Number Forms (`U+A830`..=`U+A83F`)
+    CommonIndicNumberForms,
+    /// Phags-pa (`U+A840`..=`U+A87F`)
+    PhagsPa,
+    /// Saurashtra (`U+A880`..=`U+A8DF`)
+    Saurashtra,
+    /// Devanagari Extended (`U+A8E0`..=`U+A8FF`)
+    DevanagariExtended,
+    /// Kayah Li (`U+A900`..=`U+A92F`)
+    KayahLi,
+    /// Rejang (`U+A930`..=`U+A95F`)
+    Rejang,
+    /// Hangul Jamo Extended-A (`U+A960`..=`U+A97F`)
+    HangulJamoExtendedA,
+    /// Javanese (`U+A980`..=`U+A9DF`)
+    Javanese,
+    /// Myanmar Extended-B (`U+A9E0`..=`U+A9FF`)
+    MyanmarExtendedB,
+    /// Cham (`U+AA00`..=`U+AA5F`)
+    Cham,
+    /// Myanmar Extended-A (`U+AA60`..=`U+AA7F`)
+    MyanmarExtendedA,
+    /// Tai Viet (`U+AA80`..=`U+AADF`)
+    TaiViet,
+    /// Meetei Mayek Extensions (`U+AAE0`..=`U+AAFF`)
+    MeeteiMayekExtensions,
+    /// Ethiopic Extended-A (`U+AB00`..=`U+AB2F`)
+    EthiopicExtendedA,
+    /// Latin Extended-E (`U+AB30`..=`U+AB6F`)
+    LatinExtendedE,
+    /// Cherokee Supplement (`U+AB70`..=`U+ABBF`)
+    CherokeeSupplement,
+    /// Meetei Mayek (`U+ABC0`..=`U+ABFF`)
+    MeeteiMayek,
+    /// Hangul Syllables (`U+AC00`..=`U+D7AF`)
+    HangulSyllables,
+    /// Hangul Jamo Extended-B (`U+D7B0`..=`U+D7FF`)
+    HangulJamoExtendedB,
+    /// Private Use Area (`U+E000`..=`U+F8FF`)
+    PrivateUseArea,
+    /// CJK Compatibility Ideographs (`U+F900`..=`U+FAFF`)
+    CjkCompatibilityIdeographs,
+    /// Alphabetic Presentation Forms (`U+FB00`..=`U+FB4F`)
+    AlphabeticPresentationForms,
+    /// Arabic Presentation Forms-A (`U+FB50`..=`U+FDFF`)
+    ArabicPresentationFormsA,
+    /// Variation Selectors (`U+FE00`..=`U+FE0F`)
+    VariationSelectors,
+    /// Vertical Forms (`U+FE10`..=`U+FE1F`)
+    VerticalForms,
+    /// Combining Half Marks (`U+FE20`..=`U+FE2F`)
+    CombiningHalfMarks,
+    /// CJK Compatibility Forms (`U+FE30`..=`U+FE4F`)
+    CjkCompatibilityForms,
+    /// Small Form Variants (`U+FE50`..=`U+FE6F`)
+    SmallFormVariants,
+    /// Arabic Presentation Forms-B (`U+FE70`..=`U+FEFF`)
+    ArabicPresentationFormsB,
+    /// Halfwidth and Fullwidth Forms (`U+FF00`..=`U+FFEF`)
+    HalfwidthAndFullwidthForms,
+    /// Specials (`U+FFF0`..=`U+FFFF`)
+    Specials,
+    /// Linear B Syllabary (`U+10000`..=`U+1007F`)
+    LinearBSyllabary,
+    /// Linear B Ideograms (`U+10080`..=`U+100FF`)
+    LinearBIdeograms,
+    /// Aegean Numbers (`U+10100`..=`U+1013F`)
+    AegeanNumbers,
+    /// Ancient Greek Numbers (`U+10140`..=`U+1018F`)
+    AncientGreekNumbers,
+    /// Ancient Symbols (`U+10190`..=`U+101CF`)
+    AncientSymbols,
+    /// Phaistos Disc (`U+101D0`..=`U+101FF`)
+    PhaistosDisc,
+    /// Lycian (`U+10280`..=`U+1029F`)
+    Lycian,
+    /// Carian (`U+102A0`..=`U+102DF`)
+    Carian,
+    /// Coptic Epact Numbers (`U+102E0`..=`U+102FF`)
+    CopticEpactNumbers,
+    /// Old Italic (`U+10300`..=`U+1032F`)
+    OldItalic,
+    /// Gothic (`U+10330`..=`U+1034F`)
+    Gothic,
+    /// Old Permic (`U+10350`..=`U+1037F`)
+    OldPermic,
+    /// Ugaritic (`U+10380`..=`U+1039F`)
+    Ugaritic,
+    /// Old Persian (`U+103A0`..=`U+103DF`)
+    OldPersian,
+    /// Deseret (`U+10400`..=`U+1044F`)
+    Deseret,
+    /// Shavian (`U+10450`..=`U+1047F`)
+    Shavian,
+    /// Osmanya (`U+10480`..=`U+104AF`)
+    Osmanya,
+    /// Osage (`U+104B0`..=`U+104FF`)
+    Osage,
+    /// Elbasan (`U+10500`..=`U+1052F`)
+    Elbasan,
+    /// Caucasian Albanian (`U+10530`..=`U+1056F`)
+    CaucasianAlbanian,
+    /// Vithkuqi (`U+10570`..=`U+105BF`)
+    Vithkuqi,
+    /// Todhri (`U+105C0`..=`U+105FF`)
+    Todhri,
+    /// Linear A (`U+10600`..=`U+1077F`)
+    LinearA,
+    /// Latin Extended-F (`U+10780`..=`U+107BF`)
+    LatinExtendedF,
+    /// Cypriot Syllabary (`U+10800`..=`U+1083F`)
+    CypriotSyllabary,
+    /// Imperial Aramaic (`U+10840`..=`U+1085F`)
+    ImperialAramaic,
+    /// Palmyrene (`U+10860`..=`U+1087F`)
+    Palmyrene,
+    /// Nabataean (`U+10880`..=`U+108AF`)
+    Nabataean,
+    /// Hatran (`U+108E0`..=`U+108FF`)
+    Hatran,
+    /// Phoenician (`U+10900`..=`U+1091F`)
+    Phoenician,
+    /// Lydian (`U+10920`..=`U+1093F`)
+    Lydian,
+    /// Sidetic (`U+10940`..=`U+1095F`)
+    Sidetic,
+    /// Meroitic Hieroglyphs (`U+10980`..=`U+1099F`)
+    MeroiticHieroglyphs,
+    /// Meroitic Cursive (`U+109A0`..=`U+109FF`)
+    MeroiticCursive,
+    /// Kharoshthi (`U+10A00`..=`U+10A5F`)
+    Kharoshthi,
+    /// Old South Arabian (`U+10A60`..=`U+10A7F`)
+    OldSouthArabian,
+    /// Old North Arabian (`U+10A80`..=`U+10A9F`)
+    OldNorthArabian,
+    /// Manichaean (`U+10AC0`..=`U+10AFF`)
+    Manichaean,
+    /// Avestan (`U+10B00`..=`U+10B3F`)
+    Avestan,
+    /// Inscriptional Parthian (`U+10B40`..=`U+10B5F`)
+    InscriptionalParthian,
+    /// Inscriptional Pahlavi (`U+10B60`..=`U+10B7F`)
+    InscriptionalPahlavi,
+    /// Psalter Pahlavi (`U+10B80`..=`U+10BAF`)
+    PsalterPahlavi,
+    /// Old Turkic (`U+10C00`..=`U+10C4F`)
+    OldTurkic,
+    /// Old Hungarian (`U+10C80`..=`U+10CFF`)
+    OldHungarian,
+    /// Hanifi Rohingya (`U+10D00`..=`U+10D3F`)
+    HanifiRohingya,
+    /// Garay (`U+10D40`..=`U+10D8F`)
+    Garay,
+    /// Rumi Numeral Symbols (`U+10E60`..=`U+10E7F`)
+    RumiNumeralSymbols,
+    /// Yezidi (`U+10E80`..=`U+10EBF`)
+    Yezidi,
+    /// Arabic Extended-C (`U+10EC0`..=`U+10EFF`)
+    ArabicExtendedC,
+    /// Old Sogdian (`U+10F00`..=`U+10F2F`)
+    OldSogdian,
+    /// Sogdian (`U+10F30`..=`U+10F6F`)
+    Sogdian,
+    /// Old Uyghur (`U+10F70`..=`U+10FAF`)
+    OldUyghur,
+    /// Chorasmian (`U+10FB0`..=`U+10FDF`)
+    Chorasmian,
+    /// Elymaic (`U+10FE0`..=`U+10FFF`)
+    Elymaic,
+    /// Brahmi (`U+11000`..=`U+1107F`)
+    Brahmi,
+    /// Kaithi (`U+11080`..=`U+110CF`)
+    Kaithi,
+    /// Sora Sompeng (`U+110D0`..=`U+110FF`)
+    SoraSompeng,
+    /// Chakma (`U+11100`..=`U+1114F`)
+    Chakma,
+    /// Mahajani (`U+11150`..=`U+1117F`)
+    Mahajani,
+    /// Sharada (`U+11180`..=`U+111DF`)
+    Sharada,
+    /// Sinhala Archaic Numbers (`U+111E0`..=`U+111FF`)
+    SinhalaArchaicNumbers,
+    /// Khojki (`U+11200`..=`U+1124F`)
+    Khojki,
+    /// Multani (`U+11280`..=`U+112AF`)
+    Multani,
+    /// Khudawadi (`U+112B0`..=`U+112FF`)
+    Khudawadi,
+    /// Grantha (`U+11300`..=`U+1137F`)
+    Grantha,
+    /// Tulu-Tigalari (`U+11380`..=`U+113FF`)
+    TuluTigalari,
+    /// Newa (`U+11400`..=`U+1147F`)
+    Newa,
+    /// Tirhuta (`U+11480`..=`U+114DF`)
+    Tirhuta,
+    /// Siddham (`U+11580`..=`U+115FF`)
+    Siddham,
+    /// Modi (`U+11600`..=`U+1165F`)
+    Modi,
+    /// Mongolian Supplement (`U+11660`..=`U+1167F`)
+    MongolianSupplement,
+    /// Takri (`U+11680`..=`U+116CF`)
+    Takri,
+    /// Myanmar Extended-C (`U+116D0`..=`U+116FF`)
+    MyanmarExtendedC,
+    /// Ahom (`U+11700`..=`U+1174F`)
+    Ahom,
+    /// Dogra (`U+11800`..=`U+1184F`)
+    Dogra,
+    /// Warang Citi (`U+118A0`..=`U+118FF`)
+    WarangCiti,
+    /// Dives Akuru (`U+11900`..=`U+1195F`)
+    DivesAkuru,
+    /// Nandinagari (`U+119A0`..=`U+119FF`)
+    Nandinagari,
+    /// Zanabazar Square (`U+11A00`..=`U+11A4F`)
+    ZanabazarSquare,
+    /// Soyombo (`U+11A50`..=`U+11AAF`)
+    Soyombo,
+    /// Unified Canadian Aboriginal Syllabics Extended-A (`U+11AB0`..=`U+11ABF`)
+    UnifiedCanadianAboriginalSyllabicsExtendedA,
+    /// Pau Cin Hau (`U+11AC0`..=`U+11AFF`)
+    PauCinHau,
+    /// Devanagari Extended-A (`U+11B00`..=`U+11B5F`)
+    DevanagariExtendedA,
+    /// Sharada Supplement (`U+11B60`..=`U+11B7F`)
+    SharadaSupplement,
+    /// Sunuwar (`U+11BC0`..=`U+11BFF`)
+    Sunuwar,
+    /// Bhaiksuki (`U+11C00`..=`U+11C6F`)
+    Bhaiksuki,
+    /// Marchen (`U+11C70`..=`U+11CBF`)
+    Marchen,
+    /// Masaram Gondi (`U+11D00`..=`U+11D5F`)
+    MasaramGondi,
+    /// Gunjala Gondi (`U+11D60`..=`U+11DAF`)
+    GunjalaGondi,
+    /// Tolong Siki (`U+11DB0`..=`U+11DEF`)
+    TolongSiki,
+    /// Makasar (`U+11EE0`..=`U+11EFF`)
+    Makasar,
+    /// Kawi (`U+11F00`..=`U+11F5F`)
+    Kawi,
+    /// Lisu Supplement (`U+11FB0`..=`U+11FBF`)
+    LisuSupplement,
+    /// Tamil Supplement (`U+11FC0`..=`U+11FFF`)
+    TamilSupplement,
+    /// Cuneiform (`U+12000`..=`U+123FF`)
+    Cuneiform,
+    /// Cuneiform Numbers and Punctuation (`U+12400`..=`U+1247F`)
+    CuneiformNumbersAndPunctuation,
+    /// Early Dynastic Cuneiform (`U+12480`..=`U+1254F`)
+    EarlyDynasticCuneiform,
+    /// Cypro-Minoan (`U+12F90`..=`U+12FFF`)
+    CyproMinoan,
+    /// Egyptian Hieroglyphs (`U+13000`..=`U+1342F`)
+    EgyptianHieroglyphs,
+    /// Egyptian Hieroglyph Format Controls (`U+13430`..=`U+1345F`)
+    EgyptianHieroglyphFormatControls,
+    /// Egyptian Hieroglyphs Extended-A (`U+13460`..=`U+143FF`)
+    EgyptianHieroglyphsExtendedA,
+    /// Anatolian Hieroglyphs (`U+14400`..=`U+1467F`)
+    AnatolianHieroglyphs,
+    /// Gurung Khema (`U+16100`..=`U+1613F`)
+    GurungKhema,
+    /// Bamum Supplement (`U+16800`..=`U+16A3F`)
+    BamumSupplement,
+    /// Mro (`U+16A40`..=`U+16A6F`)
+    Mro,
+    /// Tangsa (`U+16A70`..=`U+16ACF`)
+    Tangsa,
+    /// Bassa Vah (`U+16AD0`..=`U+16AFF`)
+    BassaVah,
+    /// Pahawh Hmong (`U+16B00`..=`U+16B8F`)
+    PahawhHmong,
+    /// Kirat Rai (`U+16D40`..=`U+16D7F`)
+    KiratRai,
+    /// Medefaidrin (`U+16E40`..=`U+16E9F`)
+    Medefaidrin,
+    /// Beria Erfe (`U+16EA0`..=`U+16EDF`)
+    BeriaErfe,
+    /// Miao (`U+16F00`..=`U+16F9F`)
+    Miao,
+    /// Ideographic Symbols and Punctuation (`U+16FE0`..=`U+16FFF`)
+    IdeographicSymbolsAndPunctuation,
+    /// Tangut (`U+17000`..=`U+187FF`)
+    Tangut,
+    /// Tangut Components (`U+18800`..=`U+18AFF`)
+    TangutComponents,
+    /// Khitan Small Script (`U+18B00`..=`U+18CFF`)
+    KhitanSmallScript,
+    /// Tangut Supplement (`U+18D00`..=`U+18D7F`)
+    TangutSupplement,
+    /// Tangut Components Supplement (`U+18D80`..=`U+18DFF`)
+    TangutComponentsSupplement,
+    /// Kana Extended-B (`U+1AFF0`..=`U+1AFFF`)
+    KanaExtendedB,
+    /// Kana Supplement (`U+1B000`..=`U+1B0FF`)
+    KanaSupplement,
+    /// Kana Extended-A (`U+1B100`..=`U+1B12F`)
+    KanaExtendedA,
+    /// Small Kana Extension (`U+1B130`..=`U+1B16F`)
+    SmallKanaExtension,
+    /// Nushu (`U+1B170`..=`U+1B2FF`)
+    Nushu,
+    /// Duployan (`U+1BC00`..=`U+1BC9F`)
+    Duployan,
+    /// Shorthand Format Controls (`U+1BCA0`..=`U+1BCAF`)
+    ShorthandFormatControls,
+    /// Symbols for Legacy Computing Supplement (`U+1CC00`..=`U+1CEBF`)
+    SymbolsForLegacyComputingSupplement,
+    /// Miscellaneous Symbols Supplement (`U+1CEC0`..=`U+1CEFF`)
+    MiscellaneousSymbolsSupplement,
+    /// Znamenny Musical Notation (`U+1CF00`..=`U+1CFCF`)
+    ZnamennyMusicalNotation,
+    /// Byzantine Musical Symbols (`U+1D000`..=`U+1D0FF`)
+    ByzantineMusicalSymbols,
+    /// Musical Symbols (`U+1D100`..=`U+1D1FF`)
+    MusicalSymbols,
+    /// Ancient Greek Musical Notation (`U+1D200`..=`U+1D24F`)
+    AncientGreekMusicalNotation,
+    /// Kaktovik Numerals (`U+1D2C0`..=`U+1D2DF`)
+    KaktovikNumerals,
+    /// Mayan Numerals (`U+1D2E0`..=`U+1D2FF`)
+    MayanNumerals,
+    /// Tai Xuan Jing Symbols (`U+1D300`..=`U+1D35F`)
+    TaiXuanJingSymbols,
+    /// Counting Rod Numerals (`U+1D360`..=`U+1D37F`)
+    CountingRodNumerals,
+    /// Mathematical Alphanumeric Symbols (`U+1D400`..=`U+1D7FF`)
+    MathematicalAlphanumericSymbols,
+    /// Sutton SignWriting (`U+1D800`..=`U+1DAAF`)
+    SuttonSignwriting,
+    /// Latin Extended-G (`U+1DF00`..=`U+1DFFF`)
+    LatinExtendedG,
+    /// Glagolitic Supplement (`U+1E000`..=`U+1E02F`)
+    GlagoliticSupplement,
+    /// Cyrillic Extended-D (`U+1E030`..=`U+1E08F`)
+    CyrillicExtendedD,
+    /// Nyiakeng Puachue Hmong (`U+1E100`..=`U+1E14F`)
+    NyiakengPuachueHmong,
+    /// Toto (`U+1E290`..=`U+1E2BF`)
+    Toto,
+    /// Wancho (`U+1E2C0`..=`U+1E2FF`)
+    Wancho,
+    /// Nag Mundari (`U+1E4D0`..=`U+1E4FF`)
+    NagMundari,
+    /// Ol Onal (`U+1E5D0`..=`U+1E5FF`)
+    OlOnal,
+    /// Tai Yo (`U+1E6C0`..=`U+1E6FF`)
+    TaiYo,
+    /// Ethiopic Extended-B (`U+1E7E0`..=`U+1E7FF`)
+    EthiopicExtendedB,
+    /// Mende Kikakui (`U+1E800`..=`U+1E8DF`)
+    MendeKikakui,
+    /// Adlam (`U+1E900`..=`U+1E95F`)
+    Adlam,
+    /// Indic Siyaq Numbers (`U+1EC70`..=`U+1ECBF`)
+    IndicSiyaqNumbers,
+    /// Ottoman Siyaq Numbers (`U+1ED00`..=`U+1ED4F`)
+    OttomanSiyaqNumbers,
+    /// Arabic Mathematical Alphabetic Symbols (`U+1EE00`..=`U+1EEFF`)
+    ArabicMathematicalAlphabeticSymbols,
+    /// Mahjong Tiles (`U+1F000`..=`U+1F02F`)
+    MahjongTiles,
+    /// Domino Tiles (`U+1F030`..=`U+1F09F`)
+    DominoTiles,
+    /// Playing Cards (`U+1F0A0`..=`U+1F0FF`)
+    PlayingCards,
+    /// Enclosed Alphanumeric Supplement (`U+1F100`..=`U+1F1FF`)
+    EnclosedAlphanumericSupplement,
+    /// Enclosed Ideographic Supplement (`U+1F200`..=`U+1F2FF`)
+    EnclosedIdeographicSupplement,
+    /// Miscellaneous Symbols and Pictographs (`U+1F300`..=`U+1F5FF`)
+    MiscellaneousSymbolsAndPictographs,
+    /// Emoticons (`U+1F600`..=`U+1F64F`)
+    Emoticons,
+    /// Ornamental Dingbats (`U+1F650`..=`U+1F67F`)
+    OrnamentalDingbats,
+    /// Transport and Map Symbols (`U+1F680`..=`U+1F6FF`)
+    TransportAndMapSymbols,
+    /// Alchemical Symbols (`U+1F700`..=`U+1F77F`)
+    AlchemicalSymbols,
+    /// Geometric Shapes Extended (`U+1F780`..=`U+1F7FF`)
+    GeometricShapesExtended,
+    /// Supplemental Arrows-C (`U+1F800`..=`U+1F8FF`)
+    SupplementalArrowsC,
+    /// Supplemental Symbols and Pictographs (`U+1F900`..=`U+1F9FF`)
+    SupplementalSymbolsAndPictographs,
+    /// Chess Symbols (`U+1FA00`..=`U+1FA6F`)
+    ChessSymbols,
+    /// Symbols and Pictographs Extended-A (`U+1FA70`..=`U+1FAFF`)
+    SymbolsAndPictographsExtendedA,
+    /// Symbols for Legacy Computing (`U+1FB00`..=`U+1FBFF`)
+    SymbolsForLegacyComputing,
+    /// CJK Unified Ideographs Extension B (`U+20000`..=`U+2A6DF`)
+    CjkUnifiedIdeographsExtensionB,
+    /// CJK Unified Ideographs Extension C (`U+2A700`..=`U+2B73F`)
+    CjkUnifiedIdeographsExtensionC,
+    /// CJK Unified Ideographs Extension D (`U+2B740`..=`U+2B81F`)
+    CjkUnifiedIdeographsExtensionD,
+    /// CJK Unified Ideographs Extension E (`U+2B820`..=`U+2CEAF`)
+    CjkUnifiedIdeographsExtensionE,
+    /// CJK Unified Ideographs Extension F (`U+2CEB0`..=`U+2EBEF`)
+    CjkUnifiedIdeographsExtensionF,
+    /// CJK Unified Ideographs Extension I (`U+2EBF0`..=`U+2EE5F`)
+    CjkUnifiedIdeographsExtensionI,
+    /// CJK Compatibility Ideographs Supplement (`U+2F800`..=`U+2FA1F`)
+    CjkCompatibilityIdeographsSupplement,
+    /// CJK Unified Ideographs Extension G (`U+30000`..=`U+3134F`)
+    CjkUnifiedIdeographsExtensionG,
+    /// CJK Unified Ideographs Extension H (`U+31350`..=`U+323AF`)
+    CjkUnifiedIdeographsExtensionH,
+    /// CJK Unified Ideographs Extension J (`U+323B0`..=`U+3347F`)
+    CjkUnifiedIdeographsExtensionJ,
+    /// Tags (`U+E0000`..=`U+E007F`)
+    Tags,
+    /// Variation Selectors Supplement (`U+E0100`..=`U+E01EF`)
+    VariationSelectorsSupplement,
+    /// Supplementary Private Use Area-A (`U+F0000`..=`U+FFFFF`)
+    SupplementaryPrivateUseAreaA,
+    /// Supplementary Private Use Area-B (`U+100000`..=`U+10FFFF`)
+    SupplementaryPrivateUseAreaB,
+}
+
+pub(crate) static BLOCKS: &[(Block, char, char, &str)] = &[
+    (BasicLatin, '\u{0}', '\u{7F}', "Basic Latin"),
+    (Latin1Supplement, '\u{80}', '\u{FF}', "Latin-1 Supplement"),
+    (LatinExtendedA, '\u{100}', '\u{17F}', "Latin Extended-A"),
+    (LatinExtendedB, '\u{180}', '\u{24F}', "Latin Extended-B"),
+    (IpaExtensions, '\u{250}', '\u{2AF}', "IPA Extensions"),
+    (SpacingModifierLetters, '\u{2B0}', '\u{2FF}', "Spacing Modifier Letters"),
+    (CombiningDiacriticalMarks, '\u{300}', '\u{36F}', "Combining Diacritical Marks"),
+    (GreekAndCoptic, '\u{370}', '\u{3FF}', "Greek and Coptic"),
+    (Cyrillic, '\u{400}', '\u{4FF}', "Cyrillic"),
+    (CyrillicSupplement, '\u{500}', '\u{52F}', "Cyrillic Supplement"),
+    (Armenian, '\u{530}', '\u{58F}', "Armenian"),
+    (Hebrew, '\u{590}', '\u{5FF}', "Hebrew"),
+    (Arabic, '\u{600}', '\u{6FF}', "Arabic"),
+    (Syriac, '\u{700}', '\u{74F}', "Syriac"),
+    (ArabicSupplement, '\u{750}', '\u{77F}', "Arabic Supplement"),
+    (Thaana, '\u{780}', '\u{7BF}', "Thaana"),
+    (Nko, '\u{7C0}', '\u{7FF}', "NKo"),
+    (Samaritan, '\u{800}', '\u{83F}', "Samaritan"),
+    (Mandaic, '\u{840}', '\u{85F}', "Mandaic"),
+    (SyriacSupplement, '\u{860}', '\u{86F}', "Syriac Supplement"),
+    (ArabicExtendedB, '\u{870}', '\u{89F}', "Arabic Extended-B"),
+    (ArabicExtendedA, '\u{8A0}', '\u{8FF}', "Arabic Extended-A"),
+    (Devanagari, '\u{900}', '\u{97F}', "Devanagari"),
+    (Bengali, '\u{980}', '\u{9FF}', "Bengali"),
+    (Gurmukhi, '\u{A00}', '\u{A7F}', "Gurmukhi"),
+    (Gujarati, '\u{A80}', '\u{AFF}', "Gujarati"),
+    (Oriya, '\u{B00}', '\u{B7F}', "Oriya"),
+    (Tamil, '\u{B80}', '\u{BFF}', "Tamil"),
+    (Telugu, '\u{C00}', '\u{C7F}', "Telugu"),
+    (Kannada, '\u{C80}', '\u{CFF}', "Kannada"),
+    (Malayalam, '\u{D00}', '\u{D7F}', "Malayalam"),
+    (Sinhala, '\u{D80}', '\u{DFF}', "Sinhala"),
+    (Thai, '\u{E00}', '\u{E7F}', "Thai"),
+    (Lao, '\u{E80}', '\u{EFF}', "Lao"),
+    (Tibetan, '\u{F00}', '\u{FFF}', "Tibetan"),
+    (Myanmar, '\u{1000}', '\u{109F}', "Myanmar"),
+    (Georgian, '\u{10A0}', '\u{10FF}', "Georgian"),
+    (HangulJamo, '\u{1100}', '\u{11FF}', "Hangul Jamo"),
+    (Ethiopic, '\u{1200}', '\u{137F}', "Ethiopic"),
+    (EthiopicSupplement, '\u{1380}', '\u{139F}', "Ethiopic Supplement"),
+    (Cherokee, '\u{13A0}', '\u{13FF}', "Cherokee"),
+    (UnifiedCanadianAboriginalSyllabics, '\u{1400}', '\u{167F}', "Unified Canadian Aboriginal Syllabics"),
+    (Ogham, '\u{1680}', '\u{169F}', "Ogham"),
+    (Runic, '\u{16A0}', '\u{16FF}', "Runic"),
+    (Tagalog, '\u{1700}', '\u{171F}', "Tagalog"),
+    (Hanunoo, '\u{1720}', '\u{173F}', "Hanunoo"),
+    (Buhid, '\u{1740}', '\u{175F}', "Buhid"),
+    (Tagbanwa, '\u{1760}', '\u{177F}', "Tagbanwa"),
+    (Khmer, '\u{1780}', '\u{17FF}', "Khmer"),
+    (Mongolian, '\u{1800}', '\u{18AF}', "Mongolian"),
+    (UnifiedCanadianAboriginalSyllabicsExtended, '\u{18B0}', '\u{18FF}', "Unified Canadian Aboriginal Syllabics Extended"),
+    (Limbu, '\u{1900}', '\u{194F}', "Limbu"),
+    (TaiLe, '\u{1950}', '\u{197F}', "Tai Le"),
+    (NewTaiLue, '\u{1980}', '\u{19DF}', "New Tai Lue"),
+    (KhmerSymbols, '\u{19E0}', '\u{19FF}', "Khmer Symbols"),
+    (Buginese, '\u{1A00}', '\u{1A1F}', "Buginese"),
+    (TaiTham, '\u{1A20}', '\u{1AAF}', "Tai Tham"),
+    (CombiningDiacriticalMarksExtended, '\u{1AB0}', '\u{1AFF}', "Combining Diacritical Marks Extended"),
+    (Balinese, '\u{1B00}', '\u{1B7F}', "Balinese"),
+    (Sundanese, '\u{1B80}', '\u{1BBF}', "Sundanese"),
+    (Batak, '\u{1BC0}', '\u{1BFF}', "Batak"),
+    (Lepcha, '\u{1C00}', '\u{1C4F}', "Lepcha"),
+    (OlChiki, '\u{1C50}', '\u{1C7F}', "Ol Chiki"),
+    (CyrillicExtendedC, '\u{1C80}', '\u{1C8F}', "Cyrillic Extended-C"),
+    (GeorgianExtended, '\u{1C90}', '\u{1CBF}', "Georgian Extended"),
+    (SundaneseSupplement, '\u{1CC0}', '\u{1CCF}', "Sundanese Supplement"),
+    (VedicExtensions, '\u{1CD0}', '\u{1CFF}', "Vedic Extensions"),
+    (PhoneticExtensions, '\u{1D00}', '\u{1D7F}', "Phonetic Extensions"),
+    (PhoneticExtensionsSupplement, '\u{1D80}', '\u{1DBF}', "Phonetic Extensions Supplement"),
+    (CombiningDiacriticalMarksSupplement, '\u{1DC0}', '\u{1DFF}', "Combining Diacritical Marks Supplement"),
+    (LatinExtendedAdditional, '\u{1E00}', '\u{1EFF}', "Latin Extended Additional"),
+    (GreekExtended, '\u{1F00}', '\u{1FFF}', "Greek Extended"),
+    (GeneralPunctuation, '\u{2000}', '\u{206F}', "General Punctuation"),
+    (SuperscriptsAndSubscripts, '\u{2070}', '\u{209F}', "Superscripts and Subscripts"),
+    (CurrencySymbols, '\u{20A0}', '\u{20CF}', "Currency Symbols"),
+    (CombiningDiacriticalMarksForSymbols, '\u{20D0}', '\u{20FF}', "Combining Diacritical Marks for Symbols"),
+    (LetterlikeSymbols, '\u{2100}', '\u{214F}', "Letterlike Symbols"),
+    (NumberForms, '\u{2150}', '\u{218F}', "Number Forms"),
+    (Arrows, '\u{2190}', '\u{21FF}', "Arrows"),
+    (MathematicalOperators, '\u{2200}', '\u{22FF}', "Mathematical Operators"),
+    (MiscellaneousTechnical, '\u{2300}', '\u{23FF}', "Miscellaneous Technical"),
+    (ControlPictures, '\u{2400}', '\u{243F}', "Control Pictures"),
+    (OpticalCharacterRecognition, '\u{2440}', '\u{245F}', "Optical Character Recognition"),
+    (EnclosedAlphanumerics, '\u{2460}', '\u{24FF}', "Enclosed Alphanumerics"),
+    (BoxDrawing, '\u{2500}', '\u{257F}', "Box Drawing"),
+    (BlockElements, '\u{2580}', '\u{259F}', "Block Elements"),
+    (GeometricShapes, '\u{25A0}', '\u{25FF}', "Geometric Shapes"),
+    (MiscellaneousSymbols, '\u{2600}', '\u{26FF}', "Miscellaneous Symbols"),
+    (Dingbats, '\u{2700}', '\u{27BF}', "Dingbats"),
+    (MiscellaneousMathematicalSymbolsA, '\u{27C0}', '\u{27EF}', "Miscellaneous Mathematical Symbols-A"),
+    (SupplementalArrowsA, '\u{27F0}', '\u{27FF}', "Supplemental Arrows-A"),
+    (BraillePatterns, '\u{2800}', '\u{28FF}', "Braille Patterns"),
+    (SupplementalArrowsB, '\u{2900}', '\u{297F}', "Supplemental Arrows-B"),
+    (MiscellaneousMathematicalSymbolsB, '\u{2980}', '\u{29FF}', "Miscellaneous Mathematical Symbols-B"),
+    (SupplementalMathematicalOperators, '\u{2A00}', '\u{2AFF}', "Supplemental Mathematical Operators"),
+    (MiscellaneousSymbolsAndArrows, '\u{2B00}', '\u{2BFF}', "Miscellaneous Symbols and Arrows"),
+    (Glagolitic, '\u{2C00}', '\u{2C5F}', "Glagolitic"),
+    (LatinExtendedC, '\u{2C60}', '\u{2C7F}', "Latin Extended-C"),
+    (Coptic, '\u{2C80}', '\u{2CFF}', "Coptic"),
+    (GeorgianSupplement, '\u{2D00}', '\u{2D2F}', "Georgian Supplement"),
+    (Tifinagh, '\u{2D30}', '\u{2D7F}', "Tifinagh"),
+    (EthiopicExtended, '\u{2D80}', '\u{2DDF}', "Ethiopic Extended"),
+    (CyrillicExtendedA, '\u{2DE0}', '\u{2DFF}', "Cyrillic Extended-A"),
+    (SupplementalPunctuation, '\u{2E00}', '\u{2E7F}', "Supplemental Punctuation"),
+    (CjkRadicalsSupplement, '\u{2E80}', '\u{2EFF}', "CJK Radicals Supplement"),
+    (KangxiRadicals, '\u{2F00}', '\u{2FDF}', "Kangxi Radicals"),
+    (IdeographicDescriptionCharacters, '\u{2FF0}', '\u{2FFF}', "Ideographic Description Characters"),
+    (CjkSymbolsAndPunctuation, '\u{3000}', '\u{303F}', "CJK Symbols and Punctuation"),
+    (Hiragana, '\u{3040}', '\u{309F}', "Hiragana"),
+    (Katakana, '\u{30A0}', '\u{30FF}', "Katakana"),
+    (Bopomofo, '\u{3100}', '\u{312F}', "Bopomofo"),
+    (HangulCompatibilityJamo, '\u{3130}', '\u{318F}', "Hangul Compatibility Jamo"),
+    (Kanbun, '\u{3190}', '\u{319F}', "Kanbun"),
+    (BopomofoExtended, '\u{31A0}', '\u{31BF}', "Bopomofo Extended"),
+    (CjkStrokes, '\u{31C0}', '\u{31EF}', "CJK Strokes"),
+    (KatakanaPhoneticExtensions, '\u{31F0}', '\u{31FF}', "Katakana Phonetic Extensions"),
+    (EnclosedCjkLettersAndMonths, '\u{3200}', '\u{32FF}', "Enclosed CJK Letters and Months"),
+    (CjkCompatibility, '\u{3300}', '\u{33FF}', "CJK Compatibility"),
+    (CjkUnifiedIdeographsExtensionA, '\u{3400}', '\u{4DBF}', "CJK Unified Ideographs Extension A"),
+    (YijingHexagramSymbols, '\u{4DC0}', '\u{4DFF}', "Yijing Hexagram Symbols"),
+    (CjkUnifiedIdeographs, '\u{4E00}', '\u{9FFF}', "CJK Unified Ideographs"),
+    (YiSyllables, '\u{A000}', '\u{A48F}', "Yi Syllables"),
+    (YiRadicals, '\u{A490}', '\u{A4CF}', "Yi Radicals"),
+    (Lisu, '\u{A4D0}', '\u{A4FF}', "Lisu"),
+    (Vai, '\u{A500}', '\u{A63F}', "Vai"),
+    (CyrillicExtendedB, '\u{A640}', '\u{A69F}', "Cyrillic Extended-B"),
+    (Bamum, '\u{A6A0}', '\u{A6FF}', "Bamum"),
+    (ModifierToneLetters, '\u{A700}', '\u{A71F}', "Modifier Tone Letters"),
+    (LatinExtendedD, '\u{A720}', '\u{A7FF}', "Latin Extended-D"),
+    (SylotiNagri, '\u{A800}', '\u{A82F}', "Syloti Nagri"),
+    (CommonIndicNumberForms, '\u{A830}', '\u{A83F}', "Common Indic Number Forms"),
+    (PhagsPa, '\u{A840}', '\u{A87F}', "Phags-pa"),
+    (Saurashtra, '\u{A880}', '\u{A8DF}', "Saurashtra"),
+    (DevanagariExtended, '\u{A8E0}', '\u{A8FF}', "Devanagari Extended"),
+    (KayahLi, '\u{A900}', '\u{A92F}', "Kayah Li"),
+    (Rejang, '\u{A930}', '\u{A95F}', "Rejang"),
+    (HangulJamoExtendedA, '\u{A960}', '\u{A97F}', "Hangul Jamo Extended-A"),
+    (Javanese, '\u{A980}', '\u{A9DF}', "Javanese"),
+    (MyanmarExtendedB, '\u{A9E0}', '\u{A9FF}', "Myanmar Extended-B"),
+    (Cham, '\u{AA00}', '\u{AA5F}', "Cham"),
+    (MyanmarExtendedA, '\u{AA60}', '\u{AA7F}', "Myanmar Extended-A"),
+    (TaiViet, '\u{AA80}', '\u{AADF}', "Tai Viet"),
+    (MeeteiMayekExtensions, '\u{AAE0}', '\u{AAFF}', "Meetei Mayek Extensions"),
+    (EthiopicExtendedA, '\u{AB00}', '\u{AB2F}', "Ethiopic Extended-A"),
+    (LatinExtendedE, '\u{AB30}', '\u{AB6F}', "Latin Extended-E"),
+    (CherokeeSupplement, '\u{AB70}', '\u{ABBF}', "Cherokee Supplement"),
+    (MeeteiMayek, '\u{ABC0}', '\u{ABFF}', "Meetei Mayek"),
+    (HangulSyllables, '\u{AC00}', '\u{D7AF}', "Hangul Syllables"),
+    (HangulJamoExtendedB, '\u{D7B0}', '\u{D7FF}', "Hangul Jamo Extended-B"),
+    (PrivateUseArea, '\u{E000}', '\u{F8FF}', "Private Use Area"),
+    (CjkCompatibilityIdeographs, '\u{F900}', '\u{FAFF}', "CJK Compatibility Ideographs"),
+    (AlphabeticPresentationForms, '\u{FB00}', '\u{FB4F}', "Alphabetic Presentation Forms"),
+    (ArabicPresentationFormsA, '\u{FB50}', '\u{FDFF}', "Arabic Presentation Forms-A"),
+    (VariationSelectors, '\u{FE00}', '\u{FE0F}', "Variation Selectors"),
+    (VerticalForms, '\u{FE10}', '\u{FE1F}', "Vertical Forms"),
+    (CombiningHalfMarks, '\u{FE20}', '\u{FE2F}', "Combining Half Marks"),
+    (CjkCompatibilityForms, '\u{FE30}', '\u{FE4F}', "CJK Compatibility Forms"),
+    (SmallFormVariants, '\u{FE50}', '\u{FE6F}', "Small Form Variants"),
+    (ArabicPresentationFormsB, '\u{FE70}', '\u{FEFF}', "Arabic Presentation Forms-B"),
+    (HalfwidthAndFullwidthForms, '\u{FF00}', '\u{FFEF}', "Halfwidth and Fullwidth Forms"),
+    (Specials, '\u{FFF0}', '\u{FFFF}', "Specials"),
+    (LinearBSyllabary, '\u{10000}', '\u{1007F}', "Linear B Syllabary"),
+    (LinearBIdeograms, '\u{10080}', '\u{100FF}', "Linear B Ideograms"),
+    (AegeanNumbers, '\u{10100}', '\u{1013F}', "Aegean Numbers"),
+    (AncientGreekNumbers, '\u{10140}', '\u{1018F}', "Ancient Greek Numbers"),
+    (AncientSymbols, '\u{10190}', '\u{101CF}', "Ancient Symbols"),
+    (PhaistosDisc, '\u{101D0}', '\u{101FF}', "Phaistos Disc"),
+    (Lycian, '\u{10280}', '\u{1029F}', "Lycian"),
+    (Carian, '\u{102A0}', '\u{102DF}', "Carian"),
+    (CopticEpactNumbers, '\u{102E0}', '\u{102FF}', "Coptic Epact Numbers"),
+    (OldItalic, '\u{10300}', '\u{1032F}', "Old Italic"),
+    (Gothic, '\u{10330}', '\u{1034F}', "Gothic"),
+    (OldPermic, '\u{10350}', '\u{1037F}', "Old Permic"),
+    (Ugaritic, '\u{10380}', '\u{1039F}', "Ugaritic"),
+    (OldPersian, '\u{103A0}', '\u{103DF}', "Old Persian"),
+    (Deseret, '\u{10400}', '\u{1044F}', "Deseret"),
+    (Shavian, '\u{10450}', '\u{1047F}', "Shavian"),
+    (Osmanya, '\u{10480}', '\u{104AF}', "Osmanya"),
+    (Osage, '\u{104B0}', '\u{104FF}', "Osage"),
+    (Elbasan, '\u{10500}', '\u{1052F}', "Elbasan"),
+    (CaucasianAlbanian, '\u{10530}', '\u{1056F}', "Caucasian Albanian"),
+    (Vithkuqi, '\u{10570}', '\u{105BF}', "Vithkuqi"),
+    (Todhri, '\u{105C0}', '\u{105FF}', "Todhri"),
+    (LinearA, '\u{10600}', '\u{1077F}', "Linear A"),
+    (LatinExtendedF, '\u{10780}', '\u{107BF}', "Latin Extended-F"),
+    (CypriotSyllabary, '\u{10800}', '\u{1083F}', "Cypriot Syllabary"),
+    (ImperialAramaic, '\u{10840}', '\u{1085F}', "Imperial Aramaic"),
+    (Palmyrene, '\u{10860}', '\u{1087F}', "Palmyrene"),
+    (Nabataean, '\u{10880}', '\u{108AF}', "Nabataean"),
+    (Hatran, '\u{108E0}', '\u{108FF}', "Hatran"),
+    (Phoenician, '\u{10900}', '\u{1091F}', "Phoenician"),
+    (Lydian, '\u{10920}', '\u{1093F}', "Lydian"),
+    (Sidetic, '\u{10940}', '\u{1095F}', "Sidetic"),
+    (MeroiticHieroglyphs, '\u{10980}', '\u{1099F}', "Meroitic Hieroglyphs"),
+    (MeroiticCursive, '\u{109A0}', '\u{109FF}', "Meroitic Cursive"),
+    (Kharoshthi, '\u{10A00}', '\u{10A5F}', "Kharoshthi"),
+    (OldSouthArabian, '\u{10A60}', '\u{10A7F}', "Old South Arabian"),
+    (OldNorthArabian, '\u{10A80}', '\u{10A9F}', "Old North Arabian"),
+    (Manichaean, '\u{10AC0}', '\u{10AFF}', "Manichaean"),
+    (Avestan, '\u{10B00}', '\u{10B3F}', "Avestan"),
+    (InscriptionalParthian, '\u{10B40}', '\u{10B5F}', "Inscriptional Parthian"),
+    (InscriptionalPahlavi, '\u{10B60}', '\u{10B7F}', "Inscriptional Pahlavi"),
+    (PsalterPahlavi, '\u{10B80}', '\u{10BAF}', "Psalter Pahlavi"),
+    (OldTurkic, '\u{10C00}', '\u{10C4F}', "Old Turkic"),
+    (OldHungarian, '\u{10C80}', '\u{10CFF}', "Old Hungarian"),
+    (HanifiRohingya, '\u{10D00}', '\u{10D3F}', "Hanifi Rohingya"),
+    (Garay, '\u{10D40}', '\u{10D8F}', "Garay"),
+    (RumiNumeralSymbols, '\u{10E60}', '\u{10E7F}', "Rumi Numeral Symbols"),
+    (Yezidi, '\u{10E80}', '\u{10EBF}', "Yezidi"),
+    (ArabicExtendedC, '\u{10EC0}', '\u{10EFF}', "Arabic Extended-C"),
+    (OldSogdian, '\u{10F00}', '\u{10F2F}', "Old Sogdian"),
+    (Sogdian, '\u{10F30}', '\u{10F6F}', "Sogdian"),
+    (OldUyghur, '\u{10F70}', '\u{10FAF}', "Old Uyghur"),
+    (Chorasmian, '\u{10FB0}', '\u{10FDF}', "Chorasmian"),
+    (Elymaic, '\u{10FE0}', '\u{10FFF}', "Elymaic"),
+    (Brahmi, '\u{11000}', '\u{1107F}', "Brahmi"),
+    (Kaithi, '\u{11080}', '\u{110CF}', "Kaithi"),
+    (SoraSompeng, '\u{110D0}', '\u{110FF}', "Sora Sompeng"),
+    (Chakma, '\u{11100}', '\u{1114F}', "Chakma"),
+    (Mahajani, '\u{11150}', '\u{1117F}', "Mahajani"),
+    (Sharada, '\u{11180}', '\u{111DF}', "Sharada"),
+    (SinhalaArchaicNumbers, '\u{111E0}', '\u{111FF}', "Sinhala Archaic Numbers"),
+    (Khojki, '\u{11200}', '\u{1124F}', "Khojki"),
+    (Multani, '\u{11280}', '\u{112AF}', "Multani"),
+    (Khudawadi, '\u{112B0}', '\u{112FF}', "Khudawadi"),
+    (Grantha, '\u{11300}', '\u{1137F}', "Grantha"),
+    (TuluTigalari, '\u{11380}', '\u{113FF}', "Tulu-Tigalari"),
+    (Newa, '\u{11400}', '\u{1147F}', "Newa"),
+    (Tirhuta, '\u{11480}', '\u{114DF}', "Tirhuta"),
+    (Siddham, '\u{11580}', '\u{115FF}', "Siddham"),
+    (Modi, '\u{11600}', '\u{1165F}', "Modi"),
+    (MongolianSupplement, '\u{11660}', '\u{1167F}', "Mongolian Supplement"),
+    (Takri, '\u{11680}', '\u{116CF}', "Takri"),
+    (MyanmarExtendedC, '\u{116D0}', '\u{116FF}', "Myanmar Extended-C"),
+    (Ahom, '\u{11700}', '\u{1174F}', "Ahom"),
+    (Dogra, '\u{11800}', '\u{1184F}', "Dogra"),
+    (WarangCiti, '\u{118A0}', '\u{118FF}', "Warang Citi"),
+    (DivesAkuru, '\u{11900}', '\u{1195F}', "Dives Akuru"),
+    (Nandinagari, '\u{119A0}', '\u{119FF}', "Nandinagari"),
+    (ZanabazarSquare, '\u{11A00}', '\u{11A4F}', "Zanabazar Square"),
+    (Soyombo, '\u{11A50}', '\u{11AAF}', "Soyombo"),
+    (UnifiedCanadianAboriginalSyllabicsExtendedA, '\u{11AB0}', '\u{11ABF}', "Unified Canadian Aboriginal Syllabics Extended-A"),
+    (PauCinHau, '\u{11AC0}', '\u{11AFF}', "Pau Cin Hau"),
+    (DevanagariExtendedA, '\u{11B00}', '\u{11B5F}', "Devanagari Extended-A"),
+    (SharadaSupplement, '\u{11B60}', '\u{11B7F}', "Sharada Supplement"),
+    (Sunuwar, '\u{11BC0}', '\u{11BFF}', "Sunuwar"),
+    (Bhaiksuki, '\u{11C00}', '\u{11C6F}', "Bhaiksuki"),
+    (Marchen, '\u{11C70}', '\u{11CBF}', "Marchen"),
+    (MasaramGondi, '\u{11D00}', '\u{11D5F}', "Masaram Gondi"),
+    (GunjalaGondi, '\u{11D60}', '\u{11DAF}', "Gunjala Gondi"),
+    (TolongSiki, '\u{11DB0}', '\u{11DEF}', "Tolong Siki"),
+    (Makasar, '\u{11EE0}', '\u{11EFF}', "Makasar"),
+    (Kawi, '\u{11F00}', '\u{11F5F}', "Kawi"),
+    (LisuSupplement, '\u{11FB0}', '\u{11FBF}', "Lisu Supplement"),
+    (TamilSupplement, '\u{11FC0}', '\u{11FFF}', "Tamil Supplement"),
+    (Cuneiform, '\u{12000}', '\u{123FF}', "Cuneiform"),
+    (CuneiformNumbersAndPunctuation, '\u{12400}', '\u{1247F}', "Cuneiform Numbers and Punctuation"),
+    (EarlyDynasticCuneiform, '\u{12480}', '\u{1254F}', "Early Dynastic Cuneiform"),
+    (CyproMinoan, '\u{12F90}', '\u{12FFF}', "Cypro-Minoan"),
+    (EgyptianHieroglyphs, '\u{13000}', '\u{1342F}', "Egyptian Hieroglyphs"),
+    (EgyptianHieroglyphFormatControls, '\u{13430}', '\u{1345F}', "Egyptian Hieroglyph Format Controls"),
+    (EgyptianHieroglyphsExtendedA, '\u{13460}', '\u{143FF}', "Egyptian Hieroglyphs Extended-A"),
+    (AnatolianHieroglyphs, '\u{14400}', '\u{1467F}', "Anatolian Hieroglyphs"),
+    (GurungKhema, '\u{16100}', '\u{1613F}', "Gurung Khema"),
+    (BamumSupplement, '\u{16800}', '\u{16A3F}', "Bamum Supplement"),
+    (Mro, '\u{16A40}', '\u{16A6F}', "Mro"),
+    (Tangsa, '\u{16A70}', '\u{16ACF}', "Tangsa"),
+    (BassaVah, '\u{16AD0}', '\u{16AFF}', "Bassa Vah"),
+    (PahawhHmong, '\u{16B00}', '\u{16B8F}', "Pahawh Hmong"),
+    (KiratRai, '\u{16D40}', '\u{16D7F}', "Kirat Rai"),
+    (Medefaidrin, '\u{16E40}', '\u{16E9F}', "Medefaidrin"),
+    (BeriaErfe, '\u{16EA0}', '\u{16EDF}', "Beria Erfe"),
+    (Miao, '\u{16F00}', '\u{16F9F}', "Miao"),
+    (IdeographicSymbolsAndPunctuation, '\u{16FE0}', '\u{16FFF}', "Ideographic Symbols and Punctuation"),
+    (Tangut, '\u{17000}', '\u{187FF}', "Tangut"),
+    (TangutComponents, '\u{18800}', '\u{18AFF}', "Tangut Components"),
+    (KhitanSmallScript, '\u{18B00}', '\u{18CFF}', "Khitan Small Script"),
+    (TangutSupplement, '\u{18D00}', '\u{18D7F}', "Tangut Supplement"),
+    (TangutComponentsSupplement, '\u{18D80}', '\u{18DFF}', "Tangut Components Supplement"),
+    (KanaExtendedB, '\u{1AFF0}', '\u{1AFFF}', "Kana Extended-B"),
+    (KanaSupplement, '\u{1B000}', '\u{1B0FF}', "Kana Supplement"),
+    (KanaExtendedA, '\u{1B100}', '\u{1B12F}', "Kana Extended-A"),
+    (SmallKanaExtension, '\u{1B130}', '\u{1B16F}', "Small Kana Extension"),
+    (Nushu, '\u{1B170}', '\u{1B2FF}', "Nushu"),
+    (Duployan, '\u{1BC00}', '\u{1BC9F}', "Duployan"),
+    (ShorthandFormatControls, '\u{1BCA0}', '\u{1BCAF}', "Shorthand Format Controls"),
+    (SymbolsForLegacyComputingSupplement, '\u{1CC00}', '\u{1CEBF}', "Symbols for Legacy Computing Supplement"),
+    (MiscellaneousSymbolsSupplement, '\u{1CEC0}', '\u{1CEFF}', "Miscellaneous Symbols Supplement"),
+    (ZnamennyMusicalNotation, '\u{1CF00}', '\u{1CFCF}', "Znamenny Musical Notation"),
+    (ByzantineMusicalSymbols, '\u{1D000}', '\u{1D0FF}', "Byzantine Musical Symbols"),
+    (MusicalSymbols, '\u{1D100}', '\u{1D1FF}', "Musical Symbols"),
+    (AncientGreekMusicalNotation, '\u{1D200}', '\u{1D24F}', "Ancient Greek Musical Notation"),
+    (KaktovikNumerals, '\u{1D2C0}', '\u{1D2DF}', "Kaktovik Numerals"),
+    (MayanNumerals, '\u{1D2E0}', '\u{1D2FF}', "Mayan Numerals"),
+    (TaiXuanJingSymbols, '\u{1D300}', '\u{1D35F}', "Tai Xuan Jing Symbols"),
+    (CountingRodNumerals, '\u{1D360}', '\u{1D37F}', "Counting Rod Numerals"),
+    (MathematicalAlphanumericSymbols, '\u{1D400}', '\u{1D7FF}', "Mathematical Alphanumeric Symbols"),
+    (SuttonSignwriting, '\u{1D800}', '\u{1DAAF}', "Sutton SignWriting"),
+    (LatinExtendedG, '\u{1DF00}', '\u{1DFFF}', "Latin Extended-G"),
+    (GlagoliticSupplement, '\u{1E000}', '\u{1E02F}', "Glagolitic Supplement"),
+    (CyrillicExtendedD, '\u{1E030}', '\u{1E08F}', "Cyrillic Extended-D"),
+    (NyiakengPuachueHmong, '\u{1E100}', '\u{1E14F}', "Nyiakeng Puachue Hmong"),
+    (Toto, '\u{1E290}', '\u{1E2BF}', "Toto"),
+    (Wancho, '\u{1E2C0}', '\u{1E2FF}', "Wancho"),
+    (NagMundari, '\u{1E4D0}', '\u{1E4FF}', "Nag Mundari"),
+    (OlOnal, '\u{1E5D0}', '\u{1E5FF}', "Ol Onal"),
+    (TaiYo, '\u{1E6C0}', '\u{1E6FF}', "Tai Yo"),
+    (EthiopicExtendedB, '\u{1E7E0}', '\u{1E7FF}', "Ethiopic Extended-B"),
+    (MendeKikakui, '\u{1E800}', '\u{1E8DF}', "Mende Kikakui"),
+    (Adlam, '\u{1E900}', '\u{1E95F}', "Adlam"),
+    (IndicSiyaqNumbers, '\u{1EC70}', '\u{1ECBF}', "Indic Siyaq Numbers"),
+    (OttomanSiyaqNumbers, '\u{1ED00}', '\u{1ED4F}', "Ottoman Siyaq Numbers"),
+    (ArabicMathematicalAlphabeticSymbols, '\u{1EE00}', '\u{1EEFF}', "Arabic Mathematical Alphabetic Symbols"),
+    (MahjongTiles, '\u{1F000}', '\u{1F02F}', "Mahjong Tiles"),
+    (DominoTiles, '\u{1F030}', '\u{1F09F}', "Domino Tiles"),
+    (PlayingCards, '\u{1F0A0}', '\u{1F0FF}', "Playing Cards"),
+    (EnclosedAlphanumericSupplement, '\u{1F100}', '\u{1F1FF}', "Enclosed Alphanumeric Supplement"),
+    (EnclosedIdeographicSupplement, '\u{1F200}', '\u{1F2FF}', "Enclosed Ideographic Supplement"),
+    (MiscellaneousSymbolsAndPictographs, '\u{1F300}', '\u{1F5FF}', "Miscellaneous Symbols and Pictographs"),
+    (Emoticons, '\u{1F600}', '\u{1F64F}', "Emoticons"),
+    (OrnamentalDingbats, '\u{1F650}', '\u{1F67F}', "Ornamental Dingbats"),
+    (TransportAndMapSymbols, '\u{1F680}', '\u{1F6FF}', "Transport and Map Symbols"),
+    (AlchemicalSymbols, '\u{1F700}', '\u{1F77F}', "Alchemical Symbols"),
+    (GeometricShapesExtended, '\u{1F780}', '\u{1F7FF}', "Geometric Shapes Extended"),
+    (SupplementalArrowsC, '\u{1F800}', '\u{1F8FF}', "Supplemental Arrows-C"),
+    (SupplementalSymbolsAndPictographs, '\u{1F900}', '\u{1F9FF}', "Supplemental Symbols and Pictographs"),
+    (ChessSymbols, '\u{1FA00}', '\u{1FA6F}', "Chess Symbols"),
+    (SymbolsAndPictographsExtendedA, '\u{1FA70}', '\u{1FAFF}', "Symbols and Pictographs Extended-A"),
+    (SymbolsForLegacyComputing, '\u{1FB00}', '\u{1FBFF}', "Symbols for Legacy Computing"),
+    (CjkUnifiedIdeographsExtensionB, '\u{20000}', '\u{2A6DF}', "CJK Unified Ideographs Extension B"),
+    (CjkUnifiedIdeographsExtensionC, '\u{2A700}', '\u{2B73F}', "CJK Unified Ideographs Extension C"),
+    (CjkUnifiedIdeographsExtensionD, '\u{2B740}', '\u{2B81F}', "CJK Unified Ideographs Extension D"),
+    (CjkUnifiedIdeographsExtensionE, '\u{2B820}', '\u{2CEAF}', "CJK Unified Ideographs Extension E"),
+    (CjkUnifiedIdeographsExtensionF, '\u{2CEB0}', '\u{2EBEF}', "CJK Unified Ideographs Extension F"),
+    (CjkUnifiedIdeographsExtensionI, '\u{2EBF0}', '\u{2EE5F}', "CJK Unified Ideographs Extension I"),
+    (CjkCompatibilityIdeographsSupplement, '\u{2F800}', '\u{2FA1F}', "CJK Compatibility Ideographs Supplement"),
+    (CjkUnifiedIdeographsExtensionG, '\u{30000}', '\u{3134F}', "CJK Unified Ideographs Extension G"),
+    (CjkUnifiedIdeographsExtensionH, '\u{31350}', '\u{323AF}', "CJK Unified Ideographs Extension H"),
+    (CjkUnifiedIdeographsExtensionJ, '\u{323B0}', '\u{3347F}', "CJK Unified Ideographs Extension J"),
+    (Tags, '\u{E0000}', '\u{E007F}', "Tags"),
+    (VariationSelectorsSupplement, '\u{E0100}', '\u{E01EF}', "Variation Selectors Supplement"),
+    (SupplementaryPrivateUseAreaA, '\u{F0000}', '\u{FFFFF}', "Supplementary Private Use Area-A"),
+    (SupplementaryPrivateUseAreaB, '\u{100000}', '\u{10FFFF}', "Supplementary Private Use Area-B"),
+];
+
+// Generated from the Script property values of Unicode 17.0.0, as
+// known to icu_properties. Unknown (`Zzzz`) is omitted.
+
+/// A Unicode script (UAX #24).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Script {
+    /// Adlam (`Adlm`)
+    Adlam,
+    /// Ahom (`Ahom`)
+    Ahom,
+    /// Anatolian Hieroglyphs (`Hluw`)
+    AnatolianHieroglyphs,
+    /// Arabic (`Arab`)
+    Arabic,
+    /// Armenian (`Armn`)
+    Armenian,
+    /// Avestan (`Avst`)
+    Avestan,
+    /// Balinese (`Bali`)
+    Balinese,
+    /// Bamum (`Bamu`)
+    Bamum,
+    /// Bassa Vah (`Bass`)
+    BassaVah,
+    /// Batak (`Batk`)
+    Batak,
+    /// Bengali (`Beng`)
+    Bengali,
+    /// Beria Erfe (`Berf`)
+    BeriaErfe,
+    /// Bhaiksuki (`Bhks`)
+    Bhaiksuki,
+    /// Bopomofo (`Bopo`)
+    Bopomofo,
+    /// Brahmi (`Brah`)
+    Brahmi,
+    /// Braille (`Brai`)
+    Braille,
+    /// Buginese (`Bugi`)
+    Buginese,
+    /// Buhid (`Buhd`)
+    Buhid,
+    /// Canadian Aboriginal (`Cans`)
+    CanadianAboriginal,
+    /// Carian (`Cari`)
+    Carian,
+    /// Caucasian Albanian (`Aghb`)
+    CaucasianAlbanian,
+    /// Chakma (`Cakm`)
+    Chakma,
+    /// Cham (`Cham`)
+    Cham,
+    /// Cherokee (`Cher`)
+    Cherokee,
+    /// Chorasmian (`Chrs`)
+    Chorasmian,
+    /// Common (`Zyyy`)
+    Common,
+    /// Coptic (`Copt`)
+    Coptic,
+    /// Cuneiform (`Xsux`)
+    Cuneiform,
+    /// Cypriot (`Cprt`)
+    Cypriot,
+    /// Cypro Minoan (`Cpmn`)
+    CyproMinoan,
+    /// Cyrillic (`Cyrl`)
+    Cyrillic,
+    /// Deseret (`Dsrt`)
+    Deseret,
+    /// Devanagari (`Deva`)
+    Devanagari,
+    /// Dives Akuru (`Diak`)
+    DivesAkuru,
+    /// Dogra (`Dogr`)
+    Dogra,
+    /// Duployan (`Dupl`)
+    Duployan,
+    /// Egyptian Hieroglyphs (`Egyp`)
+    EgyptianHieroglyphs,
+    /// Elbasan (`Elba`)
+    Elbasan,
+    /// Elymaic (`Elym`)
+    Elymaic,
+    /// Ethiopic (`Ethi`)
+    Ethiopic,
+    /// Garay (`Gara`)
+    Garay,
+    /// Georgian (`Geor`)
+    Georgian,
+    /// Glagolitic (`Glag`)
+    Glagolitic,
+    /// Gothic (`Goth`)
+    Gothic,
+    /// Grantha (`Gran`)
+    Grantha,
+    /// Greek (`Grek`)
+    Greek,
+    /// Gujarati (`Gujr`)
+    Gujarati,
+    /// Gunjala Gondi (`Gong`)
+    GunjalaGondi,
+    /// Gurmukhi (`Guru`)
+    Gurmukhi,
+    /// Gurung Khema (`Gukh`)
+    GurungKhema,
+    /// Han (`Hani`)
+    Han,
+    /// Hangul (`Hang`)
+    Hangul,
+    /// Hanifi Rohingya (`Rohg`)
+    HanifiRohingya,
+    /// Hanunoo (`Hano`)
+    Hanunoo,
+    /// Hatran (`Hatr`)
+    Hatran,
+    /// Hebrew (`Hebr`)
+    Hebrew,
+    /// Hiragana (`Hira`)
+    Hiragana,
+    /// Imperial Aramaic (`Armi`)
+    ImperialAramaic,
+    /// Inherited (`Zinh`)
+    Inherited,
+    /// Inscriptional Pahlavi (`Phli`)
+    InscriptionalPahlavi,
+    /// Inscriptional Parthian (`Prti`)
+    InscriptionalParthian,
+    /// Javanese (`Java`)
+    Javanese,
+    /// Kaithi (`Kthi`)
+    Kaithi,
+    /// Kannada (`Knda`)
+    Kannada,
+    /// Katakana (`Kana`)
+    Katakana,
+    /// Kawi (`Kawi`)
+    Kawi,
+    /// Kayah Li (`Kali`)
+    KayahLi,
+    /// Kharoshthi (`Khar`)
+    Kharoshthi,
+    /// Khitan Small Script (`Kits`)
+    KhitanSmallScript,
+    /// Khmer (`Khmr`)
+    Khmer,
+    /// Khojki (`Khoj`)
+    Khojki,
+    /// Khudawadi (`Sind`)
+    Khudawadi,
+    /// Kirat Rai (`Krai`)
+    KiratRai,
+    /// Lao (`Laoo`)
+    Lao,
+    /// Latin (`Latn`)
+    Latin,
+    /// Lepcha (`Lepc`)
+    Lepcha,
+    /// Limbu (`Limb`)
+    Limbu,
+    /// Linear A (`Lina`)
+    LinearA,
+    /// Linear B (`Linb`)
+    LinearB,
+    /// Lisu (`Lisu`)
+    Lisu,
+    /// Lycian (`Lyci`)
+    Lycian,
+    /// Lydian (`Lydi`)
+    Lydian,
+    /// Mahajani (`Mahj`)
+    Mahajani,
+    /// Makasar (`Maka`)
+    Makasar,
+    /// Malayalam (`Mlym`)
+    Malayalam,
+    /// Mandaic (`Mand`)
+    Mandaic,
+    /// Manichaean (`Mani`)
+    Manichaean,
+    /// Marchen (`Marc`)
+    Marchen,
+    /// Masaram Gondi (`Gonm`)
+    MasaramGondi,
+    /// Medefaidrin (`Medf`)
+    Medefaidrin,
+    /// Meetei Mayek (`Mtei`)
+    MeeteiMayek,
+    /// Mende Kikakui (`Mend`)
+    MendeKikakui,
+    /// Meroitic Cursive (`Merc`)
+    MeroiticCursive,
+    /// Meroitic Hieroglyphs (`Mero`)
+    MeroiticHieroglyphs,
+    /// Miao (`Plrd`)
+    Miao,
+    /// Modi (`Modi`)
+    Modi,
+    /// Mongolian (`Mong`)
+    Mongolian,
+    /// Mro (`Mroo`)
+    Mro,
+    /// Multani (`Mult`)
+    Multani,
+    /// Myanmar (`Mymr`)
+    Myanmar,
+    /// Nabataean (`Nbat`)
+    Nabataean,
+    /// Nag Mundari (`Nagm`)
+    NagMundari,
+    /// Nandinagari (`Nand`)
+    Nandinagari,
+    /// New Tai Lue (`Talu`)
+    NewTaiLue,
+    /// Newa (`Newa`)
+    Newa,
+    /// Nko (`Nkoo`)
+    Nko,
+    /// Nushu (`Nshu`)
+    Nushu,
+    /// Nyiakeng Puachue Hmong (`Hmnp`)
+    NyiakengPuachueHmong,
+    /// Ogham (`Ogam`)
+    Ogham,
+    /// Ol Chiki (`Olck`)
+    OlChiki,
+    /// Ol Onal (`Onao`)
+    OlOnal,
+    /// Old Hungarian (`Hung`)
+    OldHungarian,
+    /// Old Italic (`Ital`)
+    OldItalic,
+    /// Old North Arabian (`Narb`)
+    OldNorthArabian,
+    /// Old Permic (`Perm`)
+    OldPermic,
+    /// Old Persian (`Xpeo`)
+    OldPersian,
+    /// Old Sogdian (`Sogo`)
+    OldSogdian,
+    /// Old South Arabian (`Sarb`)
+    OldSouthArabian,
+    /// Old Turkic (`Orkh`)
+    OldTurkic,
+    /// Old Uyghur (`Ougr`)
+    OldUyghur,
+    /// Oriya (`Orya`)
+    Oriya,
+    /// Osage (`Osge`)
+    Osage,
+    /// Osmanya (`Osma`)
+    Osmanya,
+    /// Pahawh Hmong (`Hmng`)
+    PahawhHmong,
+    /// Palmyrene (`Palm`)
+    Palmyrene,
+    /// Pau Cin Hau (`Pauc`)
+    PauCinHau,
+    /// Phags Pa (`Phag`)
+    PhagsPa,
+    /// Phoenician (`Phnx`)
+    Phoenician,
+    /// Psalter Pahlavi (`Phlp`)
+    PsalterPahlavi,
+    /// Rejang (`Rjng`)
+    Rejang,
+    /// Runic (`Runr`)
+    Runic,
+    /// Samaritan (`Samr`)
+    Samaritan,
+    /// Saurashtra (`Saur`)
+    Saurashtra,
+    /// Sharada (`Shrd`)
+    Sharada,
+    /// Shavian (`Shaw`)
+    Shavian,
+    /// Siddham (`Sidd`)
+    Siddham,
+    /// Sidetic (`Sidt`)
+    Sidetic,
+    /// SignWriting (`Sgnw`)
+    SignWriting,
+    /// Sinhala (`Sinh`)
+    Sinhala,
+    /// Sogdian (`Sogd`)
+    Sogdian,
+    /// Sora Sompeng (`Sora`)
+    SoraSompeng,
+    /// Soyombo (`Soyo`)
+    Soyombo,
+    /// Sundanese (`Sund`)
+    Sundanese,
+    /// Sunuwar (`Sunu`)
+    Sunuwar,
+    /// Syloti Nagri (`Sylo`)
+    SylotiNagri,
+    /// Syriac (`Syrc`)
+    Syriac,
+    /// Tagalog (`Tglg`)
+    Tagalog,
+    /// Tagbanwa (`Tagb`)
+    Tagbanwa,
+    /// Tai Le (`Tale`)
+    TaiLe,
+    /// Tai Tham (`Lana`)
+    TaiTham,
+    /// Tai Viet (`Tavt`)
+    TaiViet,
+    /// Tai Yo (`Tayo`)
+    TaiYo,
+    /// Takri (`Takr`)
+    Takri,
+    /// Tamil (`Taml`)
+    Tamil,
+    /// Tangsa (`Tnsa`)
+    Tangsa,
+    /// Tangut (`Tang`)
+    Tangut,
+    /// Telugu (`Telu`)
+    Telugu,
+    /// Thaana (`Thaa`)
+    Thaana,
+    /// Thai (`Thai`)
+    Thai,
+    /// Tibetan (`Tibt`)
+    Tibetan,
+    /// Tifinagh (`Tfng`)
+    Tifinagh,
+    /// Tirhuta (`Tirh`)
+    Tirhuta,
+    /// Todhri (`Todr`)
+    Todhri,
+    /// Tolong Siki (`Tols`)
+    TolongSiki,
+    /// Toto (`Toto`)
+    Toto,
+    /// Tulu Tigalari (`Tutg`)
+    TuluTigalari,
+    /// Ugaritic (`Ugar`)
+    Ugaritic,
+    /// Vai (`Vaii`)
+    Vai,
+    /// Vithkuqi (`Vith`)
+    Vithkuqi,
+    /// Wancho (`Wcho`)
+    Wancho,
+    /// Warang Citi (`Wara`)
+    WarangCiti,
+    /// Yezidi (`Yezi`)
+    Yezidi,
+    /// Yi (`Yiii`)
+    Yi,
+    /// Zanabazar Square (`Zanb`)
+    ZanabazarSquare,
+}
+
+pub(crate) static SCRIPTS: &[(Script, &str)] = &[
+    (Script::Adlam, "Adlam"),
+    (Script::Ahom, "Ahom"),
+    (Script::AnatolianHieroglyphs, "Anatolian_Hieroglyphs"),
+    (Script::Arabic, "Arabic"),
+    (Script::Armenian, "Armenian"),
+    (Script::Avestan, "Avestan"),
+    (Script::Balinese, "Balinese"),
+    (Script::Bamum, "Bamum"),
+    (Script::BassaVah, "Bassa_Vah"),
+    (Script::Batak, "Batak"),
+    (Script::Bengali, "Bengali"),
+    (Script::BeriaErfe, "Beria_Erfe"),
+    (Script::Bhaiksuki, "Bhaiksuki"),
+    (Script::Bopomofo, "Bopomofo"),
+    (Script::Brahmi, "Brahmi"),
+    (Script::Braille, "Braille"),
+    (Script::Buginese, "Buginese"),
+    (Script::Buhid, "Buhid"),
+    (Script::CanadianAboriginal, "Canadian_Aboriginal"),
+    (Script::Carian, "Carian"),
+    (Script::CaucasianAlbanian, "Caucasian_Albanian"),
+    (Script::Chakma, "Chakma"),
+    (Script::Cham, "Cham"),
+    (Script::Cherokee, "Cherokee"),
+    (Script::Chorasmian, "Chorasmian"),
+    (Script::Common, "Common"),
+    (Script::Coptic, "Coptic"),
+    (Script::Cuneiform, "Cuneiform"),
+    (Script::Cypriot, "Cypriot"),
+    (Script::CyproMinoan, "Cypro_Minoan"),
+    (Script::Cyrillic, "Cyrillic"),
+    (Script::Deseret, "Deseret"),
+    (Script::Devanagari, "Devanagari"),
+    (Script::DivesAkuru, "Dives_Akuru"),
+    (Script::Dogra, "Dogra"),
+    (Script::Duployan, "Duployan"),
+    (Script::EgyptianHieroglyphs, "Egyptian_Hieroglyphs"),
+    (Script::Elbasan, "Elbasan"),
+    (Script::Elymaic, "Elymaic"),
+    (Script::Ethiopic, "Ethiopic"),
+    (Script::Garay, "Garay"),
+    (Script::Georgian, "Georgian"),
+    (Script::Glagolitic, "Glagolitic"),
+    (Script::Gothic, "Gothic"),
+    (Script::Grantha, "Grantha"),
+    (Script::Greek, "Greek"),
+    (Script::Gujarati, "Gujarati"),
+    (Script::GunjalaGondi, "Gunjala_Gondi"),
+    (Script::Gurmukhi, "Gurmukhi"),
+    (Script::GurungKhema, "Gurung_Khema"),
+    (Script::Han, "Han"),
+    (Script::Hangul, "Hangul"),
+    (Script::HanifiRohingya, "Hanifi_Rohingya"),
+    (Script::Hanunoo, "Hanunoo"),
+    (Script::Hatran, "Hatran"),
+    (Script::Hebrew, "Hebrew"),
+    (Script::Hiragana, "Hiragana"),
+    (Script::ImperialAramaic, "Imperial_Aramaic"),
+    (Script::Inherited, "Inherited"),
+    (Script::InscriptionalPahlavi, "Inscriptional_Pahlavi"),
+    (Script::InscriptionalParthian, "Inscriptional_Parthian"),
+    (Script::Javanese, "Javanese"),
+    (Script::Kaithi, "Kaithi"),
+    (Script::Kannada, "Kannada"),
+    (Script::Katakana, "Katakana"),
+    (Script::Kawi, "Kawi"),
+    (Script::KayahLi, "Kayah_Li"),
+    (Script::Kharoshthi, "Kharoshthi"),
+    (Script::KhitanSmallScript, "Khitan_Small_Script"),
+    (Script::Khmer, "Khmer"),
+    (Script::Khojki, "Khojki"),
+    (Script::Khudawadi, "Khudawadi"),
+    (Script::KiratRai, "Kirat_Rai"),
+    (Script::Lao, "Lao"),
+    (Script::Latin, "Latin"),
+    (Script::Lepcha, "Lepcha"),
+    (Script::Limbu, "Limbu"),
+    (Script::LinearA, "Linear_A"),
+    (Script::LinearB, "Linear_B"),
+    (Script::Lisu, "Lisu"),
+    (Script::Lycian, "Lycian"),
+    (Script::Lydian, "Lydian"),
+    (Script::Mahajani, "Mahajani"),
+    (Script::Makasar, "Makasar"),
+    (Script::Malayalam, "Malayalam"),
+    (Script::Mandaic, "Mandaic"),
+    (Script::Manichaean, "Manichaean"),
+    (Script::Marchen, "Marchen"),
+    (Script::MasaramGondi, "Masaram_Gondi"),
+    (Script::Medefaidrin, "Medefaidrin"),
+    (Script::MeeteiMayek, "Meetei_Mayek"),
+    (Script::MendeKikakui, "Mende_Kikakui"),
+    (Script::MeroiticCursive, "Meroitic_Cursive"),
+    (Script::MeroiticHieroglyphs, "Meroitic_Hieroglyphs"),
+    (Script::Miao, "Miao"),
+    (Script::Modi, "Modi"),
+    (Script::Mongolian, "Mongolian"),
+    (Script::Mro, "Mro"),
+    (Script::Multani, "Multani"),
+    (Script::Myanmar, "Myanmar"),
+    (Script::Nabataean, "Nabataean"),
+    (Script::NagMundari, "Nag_Mundari"),
+    (Script::Nandinagari, "Nandinagari"),
+    (Script::NewTaiLue, "New_Tai_Lue"),
+    (Script::Newa, "Newa"),
+    (Script::Nko, "Nko"),
+    (Script::Nushu, "Nushu"),
+    (Script::NyiakengPuachueHmong, "Nyiakeng_Puachue_Hmong"),
+    (Script::Ogham, "Ogham"),
+    (Script::OlChiki, "Ol_Chiki"),
+    (Script::OlOnal, "Ol_Onal"),
+    (Script::OldHungarian, "Old_Hungarian"),
+    (Script::OldItalic, "Old_Italic"),
+    (Script::OldNorthArabian, "Old_North_Arabian"),
+    (Script::OldPermic, "Old_Permic"),
+    (Script::OldPersian, "Old_Persian"),
+    (Script::OldSogdian, "Old_Sogdian"),
+    (Script::OldSouthArabian, "Old_South_Arabian"),
+    (Script::OldTurkic, "Old_Turkic"),
+    (Script::OldUyghur, "Old_Uyghur"),
+    (Script::Oriya, "Oriya"),
+    (Script::Osage, "Osage"),
+    (Script::Osmanya, "Osmanya"),
+    (Script::PahawhHmong, "Pahawh_Hmong"),
+    (Script::Palmyrene, "Palmyrene"),
+    (Script::PauCinHau, "Pau_Cin_Hau"),
+    (Script::PhagsPa, "Phags_Pa"),
+    (Script::Phoenician, "Phoenician"),
+    (Script::PsalterPahlavi, "Psalter_Pahlavi"),
+    (Script::Rejang, "Rejang"),
+    (Script::Runic, "Runic"),
+    (Script::Samaritan, "Samaritan"),
+    (Script::Saurashtra, "Saurashtra"),
+    (Script::Sharada, "Sharada"),
+    (Script::Shavian, "Shavian"),
+    (Script::Siddham, "Siddham"),
+    (Script::Sidetic, "Sidetic"),
+    (Script::SignWriting, "SignWriting"),
+    (Script::Sinhala, "Sinhala"),
+    (Script::Sogdian, "Sogdian"),
+    (Script::SoraSompeng, "Sora_Sompeng"),
+    (Script::Soyombo, "Soyombo"),
+    (Script::Sundanese, "Sundanese"),
+    (Script::Sunuwar, "Sunuwar"),
+    (Script::SylotiNagri, "Syloti_Nagri"),
+    (Script::Syriac, "Syriac"),
+    (Script::Tagalog, "Tagalog"),
+    (Script::Tagbanwa, "Tagbanwa"),
+    (Script::TaiLe, "Tai_Le"),
+    (Script::TaiTham, "Tai_Tham"),
+    (Script::TaiViet, "Tai_Viet"),
+    (Script::TaiYo, "Tai_Yo"),
+    (Script::Takri, "Takri"),
+    (Script::Tamil, "Tamil"),
+    (Script::Tangsa, "Tangsa"),
+    (Script::Tangut, "Tangut"),
+    (Script::Telugu, "Telugu"),
+    (Script::Thaana, "Thaana"),
+    (Script::Thai, "Thai"),
+    (Script::Tibetan, "Tibetan"),
+    (Script::Tifinagh, "Tifinagh"),
+    (Script::Tirhuta, "Tirhuta"),
+    (Script::Todhri, "Todhri"),
+    (Script::TolongSiki, "Tolong_Siki"),
+    (Script::Toto, "Toto"),
+    (Script::TuluTigalari, "Tulu_Tigalari"),
+    (Script::Ugaritic, "Ugaritic"),
+    (Script::Vai, "Vai"),
+    (Script::Vithkuqi, "Vithkuqi"),
+    (Script::Wancho, "Wancho"),
+    (Script::WarangCiti, "Warang_Citi"),
+    (Script::Yezidi, "Yezidi"),
+    (Script::Yi, "Yi"),
+    (Script::ZanabazarSquare, "Zanabazar_Square"),
+];
@@ -0,0 +1,82 @@
+//! Conversions to and from the `unic-char-range` crate.
+
+use unic_char_range::{CharIter, CharRange};
+
+use Iter;
+
+impl From<CharRange> for Iter {
+    fn from(range: CharRange) -> Iter {
+        Iter {
+            start: range.low,
+            end: range.high,
+            // `unic` considers any range with `high < low` empty.
+            finished: range.high < range.low,
+        }
+    }
+}
+impl From<CharIter> for Iter {
+    fn from(iter: CharIter) -> Iter {
+        CharRange::from(iter).into()
+    }
+}
+
+impl From<Iter> for CharRange {
+    fn from(iter: Iter) -> CharRange {
+        if iter.finished {
+            CharRange::closed('\u{1}', '\u{0}')
+        } else {
+            CharRange::closed(iter.start, iter.end)
+        }
+    }
+}
+impl From<Iter> for CharIter {
+    fn from(iter: Iter) -> CharIter {
+        CharRange::from(iter).iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use unic_char_range::{CharIter, CharRange};
+    use new;
+    use Iter;
+
+    #[test]
+    fn from_range() {
+        let v: Vec<char> = Iter::from(CharRange::closed('a', 'f')).collect();
+        assert_eq!(v, &['a', 'b', 'c', 'd', 'e', 'f']);
+    }
+    #[test]
+    fn from_empty_range() {
+        let iter = Iter::from(CharRange::closed('b', 'a'));
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.count(), 0);
+    }
+    #[test]
+    fn from_partial_iter() {
+        let mut unic = CharRange::closed('\u{D7FF}', '\u{E001}').iter();
+        unic.next();
+        let v: Vec<char> = Iter::from(unic).collect();
+        assert_eq!(v, &['\u{E000}', '\u{E001}']);
+    }
+
+    #[test]
+    fn to_range() {
+        let range = CharRange::from(new('\u{D7FF}', '\u{E000}'));
+        assert_eq!(range, CharRange::closed('\u{D7FF}', '\u{E000}'));
+        assert_eq!(range.len(), 2);
+    }
+    #[test]
+    fn to_empty_range() {
+        let mut iter = new('a', 'a');
+        iter.next();
+        assert!(CharRange::from(iter).is_empty());
+    }
+    #[test]
+    fn to_iter() {
+        let mut iter = new('a', 'c');
+        iter.next_back();
+        let v: Vec<char> = CharIter::from(iter).collect();
+        assert_eq!(v, &['a', 'b']);
+    }
+}
@@ -0,0 +1,830 @@
+//! Filtering by Unicode character properties.
+
+use std::str;
+use std::sync::OnceLock;
+
+use icu_properties::props::{self as icu, BinaryProperty, GeneralCategoryGroup};
+use icu_properties::{CodePointMapData, CodePointSetData, PropertyParser};
+use regex_syntax;
+use regex_syntax::hir::{Class, ClassUnicode, ClassUnicodeRange, HirKind};
+
+use props::char_ranges;
+use tables::SCRIPTS;
+use new;
+use Iter;
+use Ranges;
+use Script;
+
+/// A Unicode General Category, or one of the groupings of several
+/// categories (like `L` for all letters).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Category {
+    /// Uppercase_Letter
+    Lu,
+    /// Lowercase_Letter
+    Ll,
+    /// Titlecase_Letter
+    Lt,
+    /// Modifier_Letter
+    Lm,
+    /// Other_Letter
+    Lo,
+    /// Nonspacing_Mark
+    Mn,
+    /// Spacing_Mark
+    Mc,
+    /// Enclosing_Mark
+    Me,
+    /// Decimal_Number
+    Nd,
+    /// Letter_Number
+    Nl,
+    /// Other_Number
+    No,
+    /// Connector_Punctuation
+    Pc,
+    /// Dash_Punctuation
+    Pd,
+    /// Open_Punctuation
+    Ps,
+    /// Close_Punctuation
+    Pe,
+    /// Initial_Punctuation
+    Pi,
+    /// Final_Punctuation
+    Pf,
+    /// Other_Punctuation
+    Po,
+    /// Math_Symbol
+    Sm,
+    /// Currency_Symbol
+    Sc,
+    /// Modifier_Symbol
+    Sk,
+    /// Other_Symbol
+    So,
+    /// Space_Separator
+    Zs,
+    /// Line_Separator
+    Zl,
+    /// Paragraph_Separator
+    Zp,
+    /// Control
+    Cc,
+    /// Format
+    Cf,
+    /// Surrogate. No `char` is a surrogate, so this is always empty.
+    Cs,
+    /// Private_Use
+    Co,
+    /// Unassigned
+    Cn,
+
+    /// Cased_Letter: `Lu`, `Ll` and `Lt`.
+    LC,
+    /// Letter: all of the `L*` categories.
+    L,
+    /// Mark: all of the `M*` categories.
+    M,
+    /// Number: all of the `N*` categories.
+    N,
+    /// Punctuation: all of the `P*` categories.
+    P,
+    /// Symbol: all of the `S*` categories.
+    S,
+    /// Separator: all of the `Z*` categories.
+    Z,
+    /// Other: all of the `C*` categories.
+    C,
+}
+
+impl Category {
+    fn to_icu(self) -> GeneralCategoryGroup {
+        use self::Category::*;
+        type G = GeneralCategoryGroup;
+        match self {
+            Lu => G::Lu, Ll => G::Ll, Lt => G::Lt, Lm => G::Lm, Lo => G::Lo,
+            Mn => G::Mn, Mc => G::Mc, Me => G::Me,
+            Nd => G::Nd, Nl => G::Nl, No => G::No,
+            Pc => G::Pc, Pd => G::Pd, Ps => G::Ps, Pe => G::Pe,
+            Pi => G::Pi, Pf => G::Pf, Po => G::Po,
+            Sm => G::Sm, Sc => G::Sc, Sk => G::Sk, So => G::So,
+            Zs => G::Zs, Zl => G::Zl, Zp => G::Zp,
+            Cc => G::Cc, Cf => G::Cf, Cs => G::Cs, Co => G::Co, Cn => G::Cn,
+            LC => G::LC, L => G::L, M => G::M, N => G::N,
+            P => G::P, S => G::S, Z => G::Z, C => G::C,
+        }
+    }
+
+    fn ranges(self) -> Vec<(char, char)> {
+        let map = CodePointMapData::<icu::GeneralCategory>::new();
+        char_ranges(map.iter_ranges_for_group(self.to_icu()))
+    }
+}
+
+/// A binary Unicode character property, like White_Space.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Property {
+    /// Alphabetic
+    Alphabetic,
+    /// ASCII_Hex_Digit
+    AsciiHexDigit,
+    /// Bidi_Mirrored
+    BidiMirrored,
+    /// Cased
+    Cased,
+    /// Case_Ignorable
+    CaseIgnorable,
+    /// Dash
+    Dash,
+    /// Default_Ignorable_Code_Point
+    DefaultIgnorableCodePoint,
+    /// Diacritic
+    Diacritic,
+    /// Emoji
+    Emoji,
+    /// Emoji_Modifier_Base
+    EmojiModifierBase,
+    /// Emoji_Presentation
+    EmojiPresentation,
+    /// Extended_Pictographic
+    ExtendedPictographic,
+    /// Extender
+    Extender,
+    /// Hex_Digit
+    HexDigit,
+    /// Ideographic
+    Ideographic,
+    /// Join_Control
+    JoinControl,
+    /// Lowercase
+    Lowercase,
+    /// Math
+    Math,
+    /// Noncharacter_Code_Point
+    NoncharacterCodePoint,
+    /// Pattern_Syntax
+    PatternSyntax,
+    /// Pattern_White_Space
+    PatternWhiteSpace,
+    /// Quotation_Mark
+    QuotationMark,
+    /// Sentence_Terminal
+    SentenceTerminal,
+    /// Soft_Dotted
+    SoftDotted,
+    /// Terminal_Punctuation
+    TerminalPunctuation,
+    /// Uppercase
+    Uppercase,
+    /// Variation_Selector
+    VariationSelector,
+    /// White_Space
+    WhiteSpace,
+    /// XID_Continue
+    XidContinue,
+    /// XID_Start
+    XidStart,
+}
+
+impl Property {
+    fn ranges(self) -> Vec<(char, char)> {
+        use self::Property::*;
+        match self {
+            Alphabetic => binary_ranges::<icu::Alphabetic>(),
+            AsciiHexDigit => binary_ranges::<icu::AsciiHexDigit>(),
+            BidiMirrored => binary_ranges::<icu::BidiMirrored>(),
+            Cased => binary_ranges::<icu::Cased>(),
+            CaseIgnorable => binary_ranges::<icu::CaseIgnorable>(),
+            Dash => binary_ranges::<icu::Dash>(),
+            DefaultIgnorableCodePoint => binary_ranges::<icu::DefaultIgnorableCodePoint>(),
+            Diacritic => binary_ranges::<icu::Diacritic>(),
+            Emoji => binary_ranges::<icu::Emoji>(),
+            EmojiModifierBase => binary_ranges::<icu::EmojiModifierBase>(),
+            EmojiPresentation => binary_ranges::<icu::EmojiPresentation>(),
+            ExtendedPictographic => binary_ranges::<icu::ExtendedPictographic>(),
+            Extender => binary_ranges::<icu::Extender>(),
+            HexDigit => binary_ranges::<icu::HexDigit>(),
+            Ideographic => binary_ranges::<icu::Ideographic>(),
+            JoinControl => binary_ranges::<icu::JoinControl>(),
+            Lowercase => binary_ranges::<icu::Lowercase>(),
+            Math => binary_ranges::<icu::Math>(),
+            NoncharacterCodePoint => binary_ranges::<icu::NoncharacterCodePoint>(),
+            PatternSyntax => binary_ranges::<icu::PatternSyntax>(),
+            PatternWhiteSpace => binary_ranges::<icu::PatternWhiteSpace>(),
+            QuotationMark => binary_ranges::<icu::QuotationMark>(),
+            SentenceTerminal => binary_ranges::<icu::SentenceTerminal>(),
+            SoftDotted => binary_ranges::<icu::SoftDotted>(),
+            TerminalPunctuation => binary_ranges::<icu::TerminalPunctuation>(),
+            Uppercase => binary_ranges::<icu::Uppercase>(),
+            VariationSelector => binary_ranges::<icu::VariationSelector>(),
+            WhiteSpace => binary_ranges::<icu::WhiteSpace>(),
+            XidContinue => binary_ranges::<icu::XidContinue>(),
+            XidStart => binary_ranges::<icu::XidStart>(),
+        }
+    }
+}
+
+/// A version of the Unicode standard, for filtering by the Age
+/// property.
+///
+/// The Age data comes from `regex-syntax`, which is at Unicode 16.0,
+/// so there is no variant for 17.0 yet.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UnicodeVersion {
+    /// Unicode 1.1
+    V1_1,
+    /// Unicode 2.0
+    V2_0,
+    /// Unicode 2.1
+    V2_1,
+    /// Unicode 3.0
+    V3_0,
+    /// Unicode 3.1
+    V3_1,
+    /// Unicode 3.2
+    V3_2,
+    /// Unicode 4.0
+    V4_0,
+    /// Unicode 4.1
+    V4_1,
+    /// Unicode 5.0
+    V5_0,
+    /// Unicode 5.1
+    V5_1,
+    /// Unicode 5.2
+    V5_2,
+    /// Unicode 6.0
+    V6_0,
+    /// Unicode 6.1
+    V6_1,
+    /// Unicode 6.2
+    V6_2,
+    /// Unicode 6.3
+    V6_3,
+    /// Unicode 7.0
+    V7_0,
+    /// Unicode 8.0
+    V8_0,
+    /// Unicode 9.0
+    V9_0,
+    /// Unicode 10.0
+    V10_0,
+    /// Unicode 11.0
+    V11_0,
+    /// Unicode 12.0
+    V12_0,
+    /// Unicode 12.1
+    V12_1,
+    /// Unicode 13.0
+    V13_0,
+    /// Unicode 14.0
+    V14_0,
+    /// Unicode 15.0
+    V15_0,
+    /// Unicode 15.1
+    V15_1,
+    /// Unicode 16.0
+    V16_0,
+}
+
+impl UnicodeVersion {
+    fn name(self) -> &'static str {
+        use self::UnicodeVersion::*;
+        match self {
+            V1_1 => "V1_1",
+            V2_0 => "V2_0",
+            V2_1 => "V2_1",
+            V3_0 => "V3_0",
+            V3_1 => "V3_1",
+            V3_2 => "V3_2",
+            V4_0 => "V4_0",
+            V4_1 => "V4_1",
+            V5_0 => "V5_0",
+            V5_1 => "V5_1",
+            V5_2 => "V5_2",
+            V6_0 => "V6_0",
+            V6_1 => "V6_1",
+            V6_2 => "V6_2",
+            V6_3 => "V6_3",
+            V7_0 => "V7_0",
+            V8_0 => "V8_0",
+            V9_0 => "V9_0",
+            V10_0 => "V10_0",
+            V11_0 => "V11_0",
+            V12_0 => "V12_0",
+            V12_1 => "V12_1",
+            V13_0 => "V13_0",
+            V14_0 => "V14_0",
+            V15_0 => "V15_0",
+            V15_1 => "V15_1",
+            V16_0 => "V16_0",
+        }
+    }
+
+    /// The characters assigned in this version or earlier.
+    ///
+    /// icu_properties has no Age data, so this comes from
+    /// regex-syntax's `\p{age=...}` classes. Parsing one is slow, so
+    /// each version is only parsed once.
+    fn ranges(self) -> &'static [(char, char)] {
+        const COUNT: usize = UnicodeVersion::V16_0 as usize + 1;
+        static AGES: [OnceLock<Vec<(char, char)>>; COUNT] = [const { OnceLock::new() }; COUNT];
+        AGES[self as usize].get_or_init(|| {
+            property_ranges(&format!(r"\p{{age={}}}", self.name()))
+        })
+    }
+}
+
+impl Script {
+    /// The long name of this script, like `"Old_Italic"`.
+    pub fn name(self) -> &'static str {
+        SCRIPTS[self as usize].1
+    }
+
+    fn ranges(self) -> Vec<(char, char)> {
+        let script = PropertyParser::<icu::Script>::new().get_strict(self.name())
+            .unwrap_or_else(|| panic!("unknown script {}", self.name()));
+        let map = CodePointMapData::<icu::Script>::new();
+        char_ranges(map.iter_ranges_for_value(script))
+    }
+}
+
+/// The sorted, disjoint ranges of characters with the binary property
+/// `P`.
+fn binary_ranges<P: BinaryProperty>() -> Vec<(char, char)> {
+    char_ranges(CodePointSetData::new::<P>().iter_ranges())
+}
+
+/// The sorted, disjoint ranges of characters matching `query`, a
+/// `\p{...}` class understood by `regex-syntax`.
+fn property_ranges(query: &str) -> Vec<(char, char)> {
+    let hir = regex_syntax::Parser::new().parse(query)
+        .unwrap_or_else(|e| panic!("invalid property query {}: {}", query, e));
+    match *hir.kind() {
+        HirKind::Class(Class::Unicode(ref class)) => {
+            class.ranges().iter().map(|r| (r.start(), r.end())).collect()
+        }
+        // single-character classes are simplified to a literal
+        HirKind::Literal(ref lit) => {
+            let c = str::from_utf8(&lit.0).unwrap().chars().next().unwrap();
+            vec![(c, c)]
+        }
+        ref kind => panic!("unexpected HIR for {}: {:?}", query, kind),
+    }
+}
+
+/// An iterator over all the characters that simple-case-fold to the
+/// same value as `c`, in increasing order, including `c` itself.
+///
+/// This is the set of characters a case-insensitive match of `c`
+/// should accept.
+///
+/// ```rust
+/// let v: Vec<char> = char_iter::fold_orbit('k').collect();
+/// assert_eq!(v, &['K', 'k', '\u{212A}']); // ..., KELVIN SIGN
+///
+/// assert_eq!(char_iter::fold_orbit('1').collect::<Vec<_>>(), &['1']);
+/// ```
+pub fn fold_orbit(c: char) -> Ranges {
+    new(c, c).case_fold_closure()
+}
+
+impl Iter {
+    /// Restrict this iterator to the characters in the general
+    /// category `cat`.
+    ///
+    /// This intersects the range with the category's table of
+    /// ranges, so the result still knows its exact length.
+    ///
+    /// ```rust
+    /// use char_iter::Category;
+    ///
+    /// let digits = char_iter::new('\u{0}', '\u{7F}').filter_category(Category::Nd);
+    /// assert_eq!(digits.len(), 10);
+    /// assert_eq!(digits.collect::<String>(), "0123456789");
+    /// ```
+    pub fn filter_category(self, cat: Category) -> Ranges {
+        Ranges::from(self).filter_category(cat)
+    }
+
+    /// Skip the characters in the general category `cat`.
+    ///
+    /// ```rust
+    /// use char_iter::Category;
+    ///
+    /// let v: String = char_iter::new('0', 'C').skip_category(Category::N).collect();
+    /// assert_eq!(v, ":;<=>?@ABC");
+    /// ```
+    pub fn skip_category(self, cat: Category) -> Ranges {
+        Ranges::from(self).skip_category(cat)
+    }
+
+    /// Restrict this iterator to the characters of the script
+    /// `script`.
+    ///
+    /// This uses the Script property, so characters shared between
+    /// scripts (like digits or U+0301 COMBINING ACUTE ACCENT) belong
+    /// to `Common` or `Inherited` rather than to each script using
+    /// them.
+    ///
+    /// ```rust
+    /// use char_iter::Script;
+    ///
+    /// // the Greek and Coptic block starts Coptic letters at U+03E2
+    /// let v: Vec<char> = char_iter::new('\u{3E0}', '\u{3E3}').filter_script(Script::Greek).collect();
+    /// assert_eq!(v, &['\u{3E0}', '\u{3E1}']);
+    /// ```
+    pub fn filter_script(self, script: Script) -> Ranges {
+        Ranges::from(self).filter_script(script)
+    }
+
+    /// Restrict this iterator to the characters with the binary
+    /// property `prop`.
+    ///
+    /// ```rust
+    /// use char_iter::Property;
+    ///
+    /// let v: Vec<char> = char_iter::new('\u{0}', '\u{7F}').filter_property(Property::WhiteSpace).collect();
+    /// assert_eq!(v, &['\t', '\n', '\u{B}', '\u{C}', '\r', ' ']);
+    /// ```
+    pub fn filter_property(self, prop: Property) -> Ranges {
+        Ranges::from(self).filter_property(prop)
+    }
+
+    /// Skip the characters with the binary property `prop`.
+    ///
+    /// ```rust
+    /// use char_iter::Property;
+    ///
+    /// let v: String = char_iter::new('Y', 'b').skip_property(Property::Alphabetic).collect();
+    /// assert_eq!(v, "[\\]^_`");
+    /// ```
+    pub fn skip_property(self, prop: Property) -> Ranges {
+        Ranges::from(self).skip_property(prop)
+    }
+
+    /// Restrict this iterator to the characters that can start an
+    /// identifier, those with the XID_Start property of UAX #31.
+    ///
+    /// ```rust
+    /// let v: String = char_iter::new('Y', 'b').xid_start_only().collect();
+    /// assert_eq!(v, "YZab");
+    /// ```
+    pub fn xid_start_only(self) -> Ranges {
+        Ranges::from(self).xid_start_only()
+    }
+
+    /// Restrict this iterator to the characters that can continue an
+    /// identifier, those with the XID_Continue property of UAX #31.
+    ///
+    /// ```rust
+    /// let v: String = char_iter::new('Y', 'b').xid_continue_only().collect();
+    /// assert_eq!(v, "YZ_ab");
+    /// ```
+    pub fn xid_continue_only(self) -> Ranges {
+        Ranges::from(self).xid_continue_only()
+    }
+
+    /// Restrict this iterator to the characters with the Emoji
+    /// property.
+    ///
+    /// Besides pictographs, this includes the digits, `#` and `*`,
+    /// which can start keycap sequences. Use `Property::EmojiPresentation`
+    /// for the characters shown as emoji by default.
+    ///
+    /// ```rust
+    /// let v: String = char_iter::new('\u{0}', '\u{7F}').emoji_only().collect();
+    /// assert_eq!(v, "#*0123456789");
+    /// ```
+    pub fn emoji_only(self) -> Ranges {
+        Ranges::from(self).emoji_only()
+    }
+
+    /// Restrict this iterator to the characters with the
+    /// Grapheme_Extend property: the combining marks and other
+    /// characters that extend a preceding grapheme cluster.
+    ///
+    /// ```rust
+    /// let v: Vec<char> = char_iter::new('a', '\u{301}').grapheme_extend_only().collect();
+    /// assert_eq!(v, &['\u{300}', '\u{301}']);
+    /// ```
+    pub fn grapheme_extend_only(self) -> Ranges {
+        Ranges::from(self).grapheme_extend_only()
+    }
+
+    /// Skip the characters with the Grapheme_Extend property, so that
+    /// every character yielded can start a grapheme cluster of its
+    /// own.
+    pub fn skip_grapheme_extend(self) -> Ranges {
+        Ranges::from(self).skip_grapheme_extend()
+    }
+
+    /// Restrict this iterator to the characters that were assigned
+    /// in `version` of Unicode or earlier.
+    ///
+    /// ```rust
+    /// use char_iter::UnicodeVersion;
+    ///
+    /// // U+20AC EURO SIGN arrived in Unicode 2.1
+    /// let old = char_iter::new('\u{20AB}', '\u{20AC}').assigned_in(UnicodeVersion::V2_0);
+    /// assert_eq!(old.collect::<Vec<_>>(), &['\u{20AB}']);
+    /// ```
+    pub fn assigned_in(self, version: UnicodeVersion) -> Ranges {
+        Ranges::from(self).assigned_in(version)
+    }
+
+    /// Restrict this iterator to assigned characters, that is, those
+    /// not in the `Cn` (Unassigned) general category.
+    ///
+    /// Noncharacters like `U+FFFF` are also `Cn`, so they are skipped
+    /// too.
+    ///
+    /// ```rust
+    /// // U+0378 and U+0379 are unassigned
+    /// let v: Vec<char> = char_iter::new('\u{377}', '\u{37A}').assigned_only().collect();
+    /// assert_eq!(v, &['\u{377}', '\u{37A}']);
+    /// ```
+    pub fn assigned_only(self) -> Ranges {
+        Ranges::from(self).assigned_only()
+    }
+
+    /// Extend this iterator to every character that simple-case-folds
+    /// to the same value as one of its characters.
+    ///
+    /// This is the union of the `fold_orbit`s of the characters, which
+    /// is what a case-insensitive character class should match.
+    ///
+    /// ```rust
+    /// let v: String = char_iter::new('j', 'k').case_fold_closure().collect();
+    /// assert_eq!(v, "JKjk\u{212A}"); // ..., KELVIN SIGN
+    /// ```
+    pub fn case_fold_closure(self) -> Ranges {
+        Ranges::from(self).case_fold_closure()
+    }
+}
+
+impl Ranges {
+    /// Restrict this iterator further, to the characters in the
+    /// general category `cat`.
+    pub fn filter_category(self, cat: Category) -> Ranges {
+        self.restrict(&cat.ranges())
+    }
+
+    /// Skip the characters in the general category `cat`.
+    pub fn skip_category(self, cat: Category) -> Ranges {
+        self.exclude(&cat.ranges())
+    }
+
+    /// Restrict this iterator further, to the characters of the
+    /// script `script`.
+    pub fn filter_script(self, script: Script) -> Ranges {
+        self.restrict(&script.ranges())
+    }
+
+    /// Restrict this iterator further, to the characters with the
+    /// binary property `prop`.
+    pub fn filter_property(self, prop: Property) -> Ranges {
+        self.restrict(&prop.ranges())
+    }
+
+    /// Skip the characters with the binary property `prop`.
+    pub fn skip_property(self, prop: Property) -> Ranges {
+        self.exclude(&prop.ranges())
+    }
+
+    /// Restrict this iterator further, to the characters with the
+    /// XID_Start property.
+    pub fn xid_start_only(self) -> Ranges {
+        self.filter_property(Property::XidStart)
+    }
+
+    /// Restrict this iterator further, to the characters with the
+    /// XID_Continue property.
+    pub fn xid_continue_only(self) -> Ranges {
+        self.filter_property(Property::XidContinue)
+    }
+
+    /// Restrict this iterator further, to the characters with the
+    /// Emoji property.
+    pub fn emoji_only(self) -> Ranges {
+        self.filter_property(Property::Emoji)
+    }
+
+    /// Restrict this iterator further, to the characters with the
+    /// Grapheme_Extend property.
+    pub fn grapheme_extend_only(self) -> Ranges {
+        self.restrict(&binary_ranges::<icu::GraphemeExtend>())
+    }
+
+    /// Skip the characters with the Grapheme_Extend property.
+    pub fn skip_grapheme_extend(self) -> Ranges {
+        self.exclude(&binary_ranges::<icu::GraphemeExtend>())
+    }
+
+    /// Restrict this iterator further, to the characters that were
+    /// assigned in `version` of Unicode or earlier.
+    pub fn assigned_in(self, version: UnicodeVersion) -> Ranges {
+        self.restrict(version.ranges())
+    }
+
+    /// Restrict this iterator further, to assigned characters.
+    pub fn assigned_only(self) -> Ranges {
+        self.exclude(&Category::Cn.ranges())
+    }
+
+    /// Extend this iterator to every character that simple-case-folds
+    /// to the same value as one of its remaining characters.
+    pub fn case_fold_closure(self) -> Ranges {
+        let mut class = ClassUnicode::new(self.ranges.iter().map(|r| ClassUnicodeRange::new(r.start, r.end)));
+        class.case_fold_simple();
+        let closure: Vec<(char, char)> = class.ranges().iter().map(|r| (r.start(), r.end())).collect();
+        Ranges::from(new('\u{0}', '\u{10FFFF}')).restrict(&closure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use new;
+
+    #[test]
+    fn ascii_letters() {
+        let v: String = new('\u{0}', '\u{7F}').filter_category(Category::Lu).collect();
+        assert_eq!(v, "ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+        assert_eq!(new('\u{0}', '\u{7F}').filter_category(Category::L).len(), 52);
+    }
+    #[test]
+    fn single_character() {
+        let v: Vec<char> = new('\u{0}', '\u{10FFFF}').filter_category(Category::Zl).collect();
+        assert_eq!(v, &['\u{2028}']);
+    }
+    #[test]
+    fn surrogate() {
+        let iter = new('\u{0}', '\u{10FFFF}').filter_category(Category::Cs);
+        assert_eq!(iter.size_hint(), (0, Some(0)));
+        assert_eq!(iter.count(), 0);
+    }
+    #[test]
+    fn private_use_rev() {
+        let v: Vec<char> = new('\u{D7FF}', '\u{E002}').filter_category(Category::Co).rev().collect();
+        assert_eq!(v, &['\u{E002}', '\u{E001}', '\u{E000}']);
+    }
+    #[test]
+    fn exact_size() {
+        for &cat in &[Category::Nd, Category::Mn, Category::Cn, Category::C] {
+            let iter = new('\u{0}', '\u{10FFFF}').filter_category(cat);
+            let len = iter.len();
+            assert_eq!(iter.count(), len);
+        }
+    }
+
+    #[test]
+    fn assigned_in() {
+        // U+1F600 GRINNING FACE is from Unicode 6.1
+        let iter = new('\u{1F600}', '\u{1F600}');
+        assert_eq!(iter.assigned_in(UnicodeVersion::V6_0).count(), 0);
+        let iter = new('\u{1F600}', '\u{1F600}');
+        assert_eq!(iter.assigned_in(UnicodeVersion::V6_1).count(), 1);
+
+        let all = new('\u{0}', '\u{10FFFF}');
+        let v: Vec<char> = all.filter_category(Category::Lu).assigned_in(UnicodeVersion::V1_1).take(3).collect();
+        assert_eq!(v, &['A', 'B', 'C']);
+    }
+    #[test]
+    fn assigned_in_grows() {
+        let old = new('\u{0}', '\u{10FFFF}').assigned_in(UnicodeVersion::V10_0).len();
+        let current = new('\u{0}', '\u{10FFFF}').assigned_in(UnicodeVersion::V16_0).len();
+        assert!(old < current);
+    }
+
+    #[test]
+    fn skip_category() {
+        let all = new('\u{0}', '\u{10FFFF}').len();
+        for &cat in &[Category::Mn, Category::Zl, Category::Cs, Category::L] {
+            let inside = new('\u{0}', '\u{10FFFF}').filter_category(cat).len();
+            let outside = new('\u{0}', '\u{10FFFF}').skip_category(cat).len();
+            assert_eq!(inside + outside, all);
+        }
+    }
+    #[test]
+    fn scripts() {
+        for (i, &(script, _)) in SCRIPTS.iter().enumerate() {
+            assert_eq!(script as usize, i);
+        }
+        let v: String = new('\u{3B0}', '\u{3B4}').filter_script(Script::Greek).collect();
+        assert_eq!(v, "ΰαβγδ");
+        let v: Vec<char> = new('\u{300}', '\u{301}').filter_script(Script::Inherited).rev().collect();
+        assert_eq!(v, &['\u{301}', '\u{300}']);
+        assert_eq!(Script::OldItalic.name(), "Old_Italic");
+    }
+    #[test]
+    fn scripts_partition() {
+        // everything but the unassigned and private use characters
+        // has a script other than Unknown
+        let total: usize = SCRIPTS.iter()
+            .map(|&(script, _)| new('\u{0}', '\u{10FFFF}').filter_script(script).len())
+            .sum();
+        let unknown = new('\u{0}', '\u{10FFFF}').filter_category(Category::Cn).len()
+            + new('\u{0}', '\u{10FFFF}').filter_category(Category::Co).len();
+        assert_eq!(total + unknown, new('\u{0}', '\u{10FFFF}').len());
+    }
+    #[test]
+    fn properties() {
+        let v: String = new('\u{0}', '\u{7F}').filter_property(Property::Dash).collect();
+        assert_eq!(v, "-");
+        let v: String = new('\u{0}', '\u{7F}').filter_property(Property::QuotationMark).collect();
+        assert_eq!(v, "\"'");
+        assert_eq!(new('\u{0}', '\u{7F}').filter_property(Property::Uppercase).len(), 26);
+        assert_eq!(new('\u{0}', '\u{7F}').filter_property(Property::Lowercase).len(), 26);
+        // NO-BREAK SPACE is white space, ZERO WIDTH SPACE is not
+        assert_eq!(new('\u{A0}', '\u{A0}').filter_property(Property::WhiteSpace).len(), 1);
+        assert_eq!(new('\u{200B}', '\u{200B}').filter_property(Property::WhiteSpace).len(), 0);
+        let v: String = new('+', '.').filter_property(Property::Math).rev().collect();
+        assert_eq!(v, "+");
+    }
+    #[test]
+    fn skip_property() {
+        let all = new('\u{0}', '\u{10FFFF}').len();
+        for &prop in &[Property::Alphabetic, Property::WhiteSpace, Property::NoncharacterCodePoint] {
+            let inside = new('\u{0}', '\u{10FFFF}').filter_property(prop).len();
+            let outside = new('\u{0}', '\u{10FFFF}').skip_property(prop).len();
+            assert_eq!(inside + outside, all);
+        }
+        assert_eq!(new('\u{0}', '\u{10FFFF}').filter_property(Property::NoncharacterCodePoint).len(), 66);
+    }
+    #[test]
+    fn xid() {
+        let v: String = new('\u{0}', '\u{7F}').xid_start_only().collect();
+        assert_eq!(v, "ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+        let v: String = new('\u{0}', '\u{7F}').xid_continue_only().take(11).collect();
+        assert_eq!(v, "0123456789A");
+        // every XID_Start character is XID_Continue
+        let start = new('\u{0}', '\u{10FFFF}').xid_start_only().len();
+        let both = new('\u{0}', '\u{10FFFF}').xid_start_only().xid_continue_only().len();
+        assert_eq!(start, both);
+        // COMBINING ACUTE ACCENT can continue but not start
+        assert_eq!(new('\u{301}', '\u{301}').xid_start_only().len(), 0);
+        assert_eq!(new('\u{301}', '\u{301}').xid_continue_only().len(), 1);
+    }
+    #[test]
+    fn emoji() {
+        // GRINNING FACE
+        let face = || new('\u{1F600}', '\u{1F600}');
+        assert_eq!(face().emoji_only().len(), 1);
+        assert_eq!(face().filter_property(Property::EmojiPresentation).len(), 1);
+        assert_eq!(face().filter_property(Property::EmojiModifierBase).len(), 0);
+        // WAVING HAND SIGN takes skin tone modifiers
+        assert_eq!(new('\u{1F44B}', '\u{1F44B}').filter_property(Property::EmojiModifierBase).len(), 1);
+        // COPYRIGHT SIGN is text by default
+        let v: Vec<char> = new('\u{A9}', '\u{A9}').emoji_only().collect();
+        assert_eq!(v, &['\u{A9}']);
+        assert_eq!(new('\u{A9}', '\u{A9}').filter_property(Property::EmojiPresentation).len(), 0);
+        // Extended_Pictographic includes unassigned code points kept
+        // for future emoji, but not the keycap bases
+        let pictographic = new('\u{0}', '\u{10FFFF}').filter_property(Property::ExtendedPictographic);
+        assert!(pictographic.len() > new('\u{0}', '\u{10FFFF}').emoji_only().len());
+        assert_eq!(new('#', '#').filter_property(Property::ExtendedPictographic).len(), 0);
+    }
+    #[test]
+    fn grapheme_extend() {
+        let marks = new('\u{0}', '\u{10FFFF}').filter_category(Category::Mn).len();
+        let extend = new('\u{0}', '\u{10FFFF}').grapheme_extend_only().len();
+        let rest = new('\u{0}', '\u{10FFFF}').skip_grapheme_extend().len();
+        // Grapheme_Extend is Mn, Me and a few others
+        assert!(extend > marks);
+        assert_eq!(extend + rest, new('\u{0}', '\u{10FFFF}').len());
+        // ZERO WIDTH NON-JOINER extends, ZERO WIDTH SPACE does not
+        let v: Vec<char> = new('\u{200B}', '\u{200C}').skip_grapheme_extend().collect();
+        assert_eq!(v, &['\u{200B}']);
+    }
+    #[test]
+    fn fold_orbit() {
+        let v: Vec<char> = super::fold_orbit('\u{212A}').collect();
+        assert_eq!(v, &['K', 'k', '\u{212A}']);
+        let v: Vec<char> = super::fold_orbit('σ').rev().collect();
+        assert_eq!(v, &['σ', 'ς', 'Σ']);
+        assert_eq!(super::fold_orbit('ß').len(), 2); // 'ß' and 'ẞ'
+        assert_eq!(super::fold_orbit('\u{10FFFF}').len(), 1);
+    }
+    #[test]
+    fn case_fold_closure() {
+        let v: String = new('a', 'c').case_fold_closure().collect();
+        assert_eq!(v, "ABCabc");
+        // only the remaining characters count
+        let mut r = Ranges::from(new('j', 'k'));
+        r.next_back();
+        assert_eq!(r.case_fold_closure().collect::<String>(), "Jj");
+        // the surrogates stay out
+        assert_eq!(new('\u{D7FF}', '\u{E000}').case_fold_closure().len(), 2);
+    }
+    #[test]
+    fn assigned_only() {
+        let iter = new('\u{0}', '\u{10FFFF}');
+        let unassigned = new('\u{0}', '\u{10FFFF}').filter_category(Category::Cn).len();
+        assert_eq!(iter.len() - unassigned, new('\u{0}', '\u{10FFFF}').assigned_only().len());
+
+        let v: Vec<char> = new('\u{FFFC}', '\u{10001}').assigned_only().rev().collect();
+        assert_eq!(v, &['\u{10001}', '\u{10000}', '\u{FFFD}', '\u{FFFC}']);
+    }
+}
@@ -0,0 +1,82 @@
+//! Filtering by display width.
+
+use unicode_width::UnicodeWidthChar;
+
+use Iter;
+#[cfg(feature = "alloc")]
+use Ranges;
+
+/// An iterator adaptor keeping the characters with a given display
+/// width, as reported by `unicode-width`.
+///
+/// This is constructed by the `filter_width` method on `Iter` and
+/// `Ranges`.
+pub struct FilterWidth<I> {
+    iter: I,
+    width: usize,
+}
+
+impl<I: Iterator<Item = char>> Iterator for FilterWidth<I> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<char> {
+        let width = self.width;
+        self.iter.by_ref().find(|c| c.width() == Some(width))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+impl<I: DoubleEndedIterator<Item = char>> DoubleEndedIterator for FilterWidth<I> {
+    fn next_back(&mut self) -> Option<char> {
+        let width = self.width;
+        self.iter.by_ref().rev().find(|c| c.width() == Some(width))
+    }
+}
+
+impl Iter {
+    /// Keep only the characters that take up `width` columns when
+    /// displayed (0, 1 or 2), using the `unicode-width` crate.
+    ///
+    /// Control characters have no width, so they never match.
+    ///
+    /// ```rust
+    /// let wide: Vec<char> = char_iter::new('\u{FF00}', '\u{FF02}').filter_width(2).collect();
+    /// assert_eq!(wide, &['\u{FF01}', '\u{FF02}']);
+    /// ```
+    pub fn filter_width(self, width: usize) -> FilterWidth<Iter> {
+        FilterWidth { iter: self, width }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Ranges {
+    /// Keep only the characters that take up `width` columns, as
+    /// `Iter::filter_width`.
+    pub fn filter_width(self, width: usize) -> FilterWidth<Ranges> {
+        FilterWidth { iter: self, width }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use new;
+
+    #[test]
+    fn ascii() {
+        assert_eq!(new('\u{0}', '\u{7F}').filter_width(1).count(), 95);
+        assert_eq!(new('\u{0}', '\u{7F}').filter_width(2).count(), 0);
+    }
+    #[test]
+    fn combining() {
+        let v: Vec<char> = new('a', '\u{301}').filter_width(0).rev().take(2).collect();
+        assert_eq!(v, &['\u{301}', '\u{300}']);
+    }
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn ranges() {
+        let v: Vec<char> = new('\u{3000}', '\u{3002}').skip_private_use().filter_width(2).collect();
+        assert_eq!(v, &['\u{3000}', '\u{3001}', '\u{3002}']);
+    }
+}